@@ -0,0 +1,34 @@
+//! A simple benchmark for evaluating expressions that do a lot of exact
+//! rational arithmetic. Run it with `cargo bench -p fend-core`.
+
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 20;
+
+const EXPRESSIONS: &[&str] = &[
+    "1/2 + 1/3 + 1/4 + 1/5 + 1/6 + 1/7 + 1/8 + 1/9 + 1/10 + 1/11 + 1/12 to fraction",
+    "(1 + 1/10)^40 to fraction",
+    "(3/7)^20 / (9/49)^10 * 22/7 - 1/3",
+    "50! / 48! / 7 to fraction",
+    "sqrt(2) * sqrt(8) / 3",
+    "2^(1/3) * 3^(1/2) to 30 dp",
+];
+
+fn time(input: &str) -> Duration {
+    let mut context = fend_core::Context::new();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        fend_core::evaluate(input, &mut context).unwrap();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    let mut total = Duration::ZERO;
+    for input in EXPRESSIONS {
+        let duration = time(input);
+        total += duration;
+        println!("{duration:>12.2?}  {input}");
+    }
+    println!("{total:>12.2?}  total");
+}
@@ -31,6 +31,8 @@ pub(crate) enum Bop {
     Bitwise(BitwiseBop),
     Combination,
     Permutation,
+    // the beta function, which is called as `beta a b`
+    Beta,
 }
 
 impl Bop {
@@ -50,6 +52,7 @@ impl Bop {
             Self::Bitwise(BitwiseBop::RightShift) => 11,
             Self::Combination => 12,
             Self::Permutation => 13,
+            Self::Beta => 14,
         };
         serialize_u8(n, write)?;
         Ok(())
@@ -71,6 +74,7 @@ impl Bop {
             11 => Self::Bitwise(BitwiseBop::RightShift),
             12 => Self::Combination,
             13 => Self::Permutation,
+            14 => Self::Beta,
             _ => return Err(FendError::DeserializationError),
         })
     }
@@ -93,6 +97,7 @@ impl fmt::Display for Bop {
             Self::Bitwise(BitwiseBop::RightShift) => ">>",
             Self::Combination => "nCr",
             Self::Permutation => "nPr",
+            Self::Beta => " beta ",
         };
         write!(f, "{s}")
     }
@@ -387,9 +392,10 @@ pub(crate) fn evaluate<I: Interrupt>(
                     return Ok(res);
                 }
             }
+            let prec = context.precision;
             lhs.handle_two_nums(
                 eval!(*b)?,
-                |a, b| a.pow(b, int),
+                |a, b| a.pow(b, prec, int),
                 |a| {
                     |f| {
                         Expr::Bop(
@@ -497,6 +503,17 @@ fn evaluate_as<I: Interrupt>(
 ) -> Result<Value, FendError> {
     if let Expr::Ident(ident) = &b {
         match ident.as_str() {
+            "percent" => {
+                // convert to a plain number first, so that e.g. `50%` isn't
+                // scaled by 100 twice
+                let num = evaluate(a, scope, attrs, context, int)?
+                    .expect_num()?
+                    .convert_to(Number::from(1), int)?
+                    .with_format(FormattingStyle::Percent(
+                        FormattingStyle::DEFAULT_PERCENT_SIG_FIGS,
+                    ));
+                return Ok(Value::Num(Box::new(num)));
+            }
             "bool" | "boolean" => {
                 let num = evaluate(a, scope, attrs, context, int)?.expect_num()?;
                 return Ok(Value::Bool(!num.is_zero()));
@@ -594,7 +611,7 @@ pub(crate) fn resolve_identifier<I: Interrupt>(
     Ok(match ident.as_str() {
         "pi" | "\u{3c0}" => Value::Num(Box::new(Number::pi())),
         "tau" | "\u{3c4}" => Value::Num(Box::new(Number::pi().mul(2.into(), int)?)),
-        "e" => evaluate_to_value("approx. 2.718281828459045235", scope, attrs, context, int)?,
+        "e" => Value::Num(Box::new(Number::from(1).exp(context.precision, int)?)),
         "phi" => evaluate_to_value("(1 + sqrt(5))/2", scope, attrs, context, int)?,
         "i" => Value::Num(Box::new(Number::i())),
         "true" => Value::Bool(true),
@@ -604,6 +621,11 @@ pub(crate) fn resolve_identifier<I: Interrupt>(
         "cbrt" => evaluate_to_value("x: x^(1/3)", scope, attrs, context, int)?,
         "conjugate" => Value::BuiltInFunction(BuiltInFunction::Conjugate),
         "abs" => Value::BuiltInFunction(BuiltInFunction::Abs),
+        "round" => Value::BuiltInFunction(BuiltInFunction::Round),
+        "floor" => Value::BuiltInFunction(BuiltInFunction::Floor),
+        "ceil" => Value::BuiltInFunction(BuiltInFunction::Ceil),
+        "trunc" => Value::BuiltInFunction(BuiltInFunction::Trunc),
+        "fract" => Value::BuiltInFunction(BuiltInFunction::Fract),
         "sin" => Value::BuiltInFunction(BuiltInFunction::Sin),
         "cos" => Value::BuiltInFunction(BuiltInFunction::Cos),
         "tan" => Value::BuiltInFunction(BuiltInFunction::Tan),
@@ -626,14 +648,22 @@ pub(crate) fn resolve_identifier<I: Interrupt>(
         "ln" => Value::BuiltInFunction(BuiltInFunction::Ln),
         "log2" => Value::BuiltInFunction(BuiltInFunction::Log2),
         "log" | "log10" => Value::BuiltInFunction(BuiltInFunction::Log10),
+        "lgamma" => Value::BuiltInFunction(BuiltInFunction::Lgamma),
+        "zeta" => Value::BuiltInFunction(BuiltInFunction::Zeta),
+        "erf" => Value::BuiltInFunction(BuiltInFunction::Erf),
+        "erfc" => Value::BuiltInFunction(BuiltInFunction::Erfc),
+        "beta" => Value::BuiltInFunction(BuiltInFunction::Beta),
         "not" => Value::BuiltInFunction(BuiltInFunction::Not),
-        "exp" => evaluate_to_value("x: e^x", scope, attrs, context, int)?,
+        "exp" => Value::BuiltInFunction(BuiltInFunction::Exp),
         "approx." | "approximately" => Value::BuiltInFunction(BuiltInFunction::Approximately),
         "auto" => Value::Format(FormattingStyle::Auto),
         "exact" => Value::Format(FormattingStyle::Exact),
         "frac" | "fraction" => Value::Format(FormattingStyle::ImproperFraction),
         "mixed_frac" | "mixed_fraction" => Value::Format(FormattingStyle::MixedFraction),
         "float" => Value::Format(FormattingStyle::ExactFloat),
+        "tally" => Value::Format(FormattingStyle::Tally),
+        "scientific" => Value::Format(FormattingStyle::Scientific),
+        "factorial_base" => Value::Format(FormattingStyle::FactorialBase),
         "dp" => Value::Dp,
         "sf" => Value::Sf,
         "base" => Value::BuiltInFunction(BuiltInFunction::Base),
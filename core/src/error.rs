@@ -85,6 +85,8 @@ pub(crate) enum FendError {
     },
     ModuloUnitless,
     RootsOfNegativeNumbers,
+    GammaPole,
+    ScientificNotationBase,
     NonIntegerNegRoots,
     CannotConvertValueTo(&'static str),
     ExpectedADateLiteral,
@@ -136,6 +138,15 @@ impl fmt::Display for FendError {
             Self::ModuloForPositiveInts => {
                 write!(f, "modulo is only supported for positive integers")
             }
+            Self::ScientificNotationBase => {
+                write!(f, "scientific notation is only supported in bases up to 14")
+            }
+            Self::GammaPole => {
+                write!(
+                    f,
+                    "the gamma function is undefined for zero and negative integers"
+                )
+            }
             Self::CannotConvertValueTo(ty) => write!(f, "cannot convert value to {ty}"),
             Self::BaseTooSmall => write!(f, "base must be at least 2"),
             Self::ConversionRhsNumerical => write!(
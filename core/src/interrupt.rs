@@ -2,6 +2,23 @@ use crate::error::FendError;
 
 pub trait Interrupt {
     fn should_interrupt(&self) -> bool;
+
+    /// Called periodically during slow computations (e.g. large factorials,
+    /// high-precision series or long recurring expansions) with a rough
+    /// estimate between 0 and 1 of how far along the computation is. This
+    /// does nothing by default.
+    fn progress(&self, fraction: f64) {
+        let _ = fraction;
+    }
+
+    /// How many iterations of a tight loop (e.g. producing the digits of a
+    /// long recurring expansion, or the word-by-word loops of big integer
+    /// arithmetic) may run between calls to `should_interrupt`. Larger values
+    /// reduce the overhead of checking at the cost of reacting to an
+    /// interrupt more slowly. Defaults to 4096; a value of 0 is treated as 1.
+    fn check_interval(&self) -> usize {
+        4096
+    }
 }
 
 pub(crate) fn test_int<I: crate::error::Interrupt>(int: &I) -> Result<(), FendError> {
@@ -12,6 +29,27 @@ pub(crate) fn test_int<I: crate::error::Interrupt>(int: &I) -> Result<(), FendEr
     }
 }
 
+/// Like `test_int`, but only checks every `check_interval` iterations. This
+/// should be used for loops where each iteration is cheap.
+pub(crate) fn test_int_every<I: crate::error::Interrupt>(
+    int: &I,
+    iteration: usize,
+) -> Result<(), FendError> {
+    if iteration.is_multiple_of(int.check_interval().max(1)) {
+        test_int(int)
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn report_progress<I: crate::error::Interrupt>(int: &I, done: u64, total: u64) {
+    if total == 0 {
+        return;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    int.progress(done.min(total) as f64 / total as f64);
+}
+
 #[derive(Default)]
 pub(crate) struct Never {}
 impl Interrupt for Never {
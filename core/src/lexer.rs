@@ -1,7 +1,7 @@
 use crate::date::Date;
 use crate::error::{FendError, Interrupt};
 use crate::ident::Ident;
-use crate::num::{Base, Number};
+use crate::num::{Base, Number, Precision};
 use std::{borrow, convert, fmt};
 
 #[derive(Clone, Debug)]
@@ -157,25 +157,25 @@ fn parse_base_prefix(input: &str) -> Result<(Base, &str), FendError> {
         let (ch, input) = parse_char(input)?;
         Ok((Base::from_zero_based_prefix_char(ch)?, input))
     } else {
+        // the base is only checked once we know this is a prefix, so that
+        // e.g. `37` is still a valid number while `37#1` is an error
         let mut custom_base: u8 = 0;
+        let mut too_large = false;
         let (_, input) = parse_integer(input, false, Base::default(), &mut |digit| -> Result<
             (),
             FendError,
         > {
-            let error = FendError::BaseTooLarge;
             if custom_base > 3 {
-                return Err(error);
-            }
-            custom_base = 10 * custom_base + digit;
-            if custom_base > 36 {
-                return Err(error);
+                too_large = true;
+            } else {
+                custom_base = 10 * custom_base + digit;
             }
             Ok(())
         })?;
-        if custom_base < 2 {
-            return Err(FendError::BaseTooSmall);
-        }
         let (_, input) = parse_fixed_char(input, '#')?;
+        if too_large {
+            return Err(FendError::BaseTooLarge);
+        }
         Ok((Base::from_custom_base(custom_base)?, input))
     }
 }
@@ -202,22 +202,17 @@ fn parse_recurring_digits<'a, I: Interrupt>(
         // return Ok if there were no digits
         return Ok(((), original_input));
     }
-    let mut recurring_number_num = Number::from(0);
-    let mut recurring_number_den = Number::from(1);
-    let base_as_u64 = u64::from(base.base_as_u8());
+    let mut recurring_digits = vec![];
     let (_, input) = parse_integer(input, true, base, &mut |digit| -> Result<(), FendError> {
-        let digit_as_u64 = u64::from(digit);
-        recurring_number_num = recurring_number_num
-            .clone()
-            .mul(base_as_u64.into(), int)?
-            .add(digit_as_u64.into(), int)?;
-        recurring_number_den = recurring_number_den.clone().mul(base_as_u64.into(), int)?;
+        recurring_digits.push(digit);
         Ok(())
     })?;
-    recurring_number_den = recurring_number_den.clone().sub(1.into(), int)?;
-    for _ in 0..num_nonrec_digits {
-        recurring_number_den = recurring_number_den.clone().mul(base_as_u64.into(), int)?;
-    }
+    // the denominator is `(base^n - 1) * base^num_nonrec_digits`, whose digits
+    // are n copies of the largest digit followed by num_nonrec_digits zeroes
+    let mut den_digits = vec![base.base_as_u8() - 1; recurring_digits.len()];
+    den_digits.resize(recurring_digits.len() + num_nonrec_digits, 0);
+    let recurring_number_num = Number::from_digits(&recurring_digits, base, int)?;
+    let recurring_number_den = Number::from_digits(&den_digits, base, int)?;
     *number = number
         .clone()
         .add(recurring_number_num.div(recurring_number_den, int)?, int)?;
@@ -244,44 +239,40 @@ fn parse_basic_number<'a, I: Interrupt>(
 
     // parse integer component
     let mut res = Number::zero_with_base(base);
-    let base_as_u64 = u64::from(base.base_as_u8());
     let mut is_integer = true;
 
     if parse_fixed_char(input, '.').is_err() && !is_dice_with_no_count {
+        let mut digits = vec![];
         let (_, remaining) =
             parse_integer(input, true, base, &mut |digit| -> Result<(), FendError> {
-                res = res
-                    .clone()
-                    .mul(base_as_u64.into(), int)?
-                    .add(u64::from(digit).into(), int)?;
+                digits.push(digit);
                 Ok(())
             })?;
+        res = Number::from_digits(&digits, base, int)?;
         input = remaining;
     }
 
     // parse decimal point and at least one digit
     if let Ok((_, remaining)) = parse_fixed_char(input, '.') {
         is_integer = false;
-        let mut num_nonrec_digits = 0;
-        let mut numerator = Number::zero_with_base(base);
-        let mut denominator = Number::zero_with_base(base).add(1.into(), int)?;
+        let mut digits = vec![];
         if parse_fixed_char(remaining, '(').is_err() {
             let (_, remaining) = parse_integer(remaining, true, base, &mut |digit| -> Result<
                 (),
                 FendError,
             > {
-                numerator = numerator
-                    .clone()
-                    .mul(base_as_u64.into(), int)?
-                    .add(u64::from(digit).into(), int)?;
-                denominator = denominator.clone().mul(base_as_u64.into(), int)?;
-                num_nonrec_digits += 1;
+                digits.push(digit);
                 Ok(())
             })?;
             input = remaining;
         } else {
             input = remaining;
         }
+        let num_nonrec_digits = digits.len();
+        let mut den_digits = vec![1];
+        den_digits.resize(num_nonrec_digits + 1, 0);
+        let numerator = Number::from_digits(&digits, base, int)?;
+        let denominator = Number::from_digits(&den_digits, base, int)?;
         res = res.add(numerator.div(denominator, int)?, int)?;
 
         // try parsing recurring decimals
@@ -367,8 +358,49 @@ fn parse_basic_number<'a, I: Interrupt>(
                 if negative_exponent {
                     exp = -exp;
                 }
-                let base_as_number: Number = base_as_u64.into();
-                res = res.mul(base_as_number.pow(exp, int)?, int)?;
+                res = res.mul(base_num.pow(exp, Precision::default(), int)?, int)?;
+                input = remaining2;
+            }
+        }
+    }
+
+    // parse optional binary exponent for hexadecimal floats, e.g. 0x1.8p3 == 12.
+    // Like in C, the exponent is a decimal power of two.
+    if base.base_as_u8() == 16 {
+        let remaining = parse_fixed_char(input, 'p')
+            .or_else(|_| parse_fixed_char(input, 'P'))
+            .map(|((), remaining)| remaining);
+        if let Ok(remaining) = remaining {
+            // only parse the exponent if it starts with a digit or sign, so
+            // that e.g. `0x2pi` is still parsed as a multiplication
+            let is_exponent = parse_char(remaining)
+                .is_ok_and(|(ch, _)| ch.is_ascii_digit() || ch == '+' || ch == '-');
+            if is_exponent {
+                input = remaining;
+                let mut negative_exponent = false;
+                if let Ok(((), remaining)) = parse_fixed_char(input, '-') {
+                    negative_exponent = true;
+                    input = remaining;
+                } else if let Ok(((), remaining)) = parse_fixed_char(input, '+') {
+                    input = remaining;
+                }
+                let mut exp = Number::from(0);
+                let ((), remaining2) =
+                    parse_integer(input, true, Base::default(), &mut |digit| -> Result<
+                        (),
+                        FendError,
+                    > {
+                        exp = exp
+                            .clone()
+                            .mul(10.into(), int)?
+                            .add(u64::from(digit).into(), int)?;
+                        Ok(())
+                    })?;
+                if negative_exponent {
+                    exp = -exp;
+                }
+                let two: Number = 2.into();
+                res = res.mul(two.pow(exp, Precision::default(), int)?, int)?;
                 input = remaining2;
             }
         }
@@ -378,7 +410,11 @@ fn parse_basic_number<'a, I: Interrupt>(
 }
 
 fn parse_number<'a, I: Interrupt>(input: &'a str, int: &I) -> Result<(Number, &'a str), FendError> {
-    let (base, input) = parse_base_prefix(input).unwrap_or((Base::default(), input));
+    let (base, input) = match parse_base_prefix(input) {
+        Ok(res) => res,
+        Err(e @ (FendError::BaseTooSmall | FendError::BaseTooLarge)) => return Err(e),
+        Err(_) => (Base::default(), input),
+    };
     let (res, input) = parse_basic_number(input, base, int)?;
     Ok((res, input))
 }
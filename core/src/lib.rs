@@ -43,6 +43,7 @@ use std::{collections::HashMap, fmt, io};
 use error::FendError;
 pub(crate) use eval::Attrs;
 pub use interrupt::Interrupt;
+pub use num::{RoundingMode, TrailingZeros};
 use serialize::{deserialize_string, deserialize_usize, serialize_string, serialize_usize};
 
 /// This contains the result of a computation.
@@ -172,6 +173,12 @@ pub struct Context {
     random_u32: Option<fn() -> u32>,
     output_mode: OutputMode,
     get_exchange_rate: Option<ExchangeRateFn>,
+    precision: num::Precision,
+    default_decimal_places: usize,
+    rounding: num::RoundingMode,
+    recurring_digit_limit: usize,
+    leading_zero: bool,
+    trailing_zeros: num::TrailingZeros,
 }
 
 impl fmt::Debug for Context {
@@ -182,6 +189,12 @@ impl fmt::Debug for Context {
             .field("fc_mode", &self.fc_mode)
             .field("random_u32", &self.random_u32)
             .field("output_mode", &self.output_mode)
+            .field("precision", &self.precision)
+            .field("default_decimal_places", &self.default_decimal_places)
+            .field("rounding", &self.rounding)
+            .field("recurring_digit_limit", &self.recurring_digit_limit)
+            .field("leading_zero", &self.leading_zero)
+            .field("trailing_zeros", &self.trailing_zeros)
             .finish_non_exhaustive()
     }
 }
@@ -204,6 +217,12 @@ impl Context {
             random_u32: None,
             output_mode: OutputMode::SimpleText,
             get_exchange_rate: None,
+            precision: num::Precision::default(),
+            default_decimal_places: num::FormattingStyle::DEFAULT_DECIMAL_PLACES,
+            rounding: num::RoundingMode::default(),
+            recurring_digit_limit: num::FormattingStyle::DEFAULT_RECURRING_DIGIT_LIMIT,
+            leading_zero: true,
+            trailing_zeros: num::TrailingZeros::default(),
         }
     }
 
@@ -247,6 +266,48 @@ impl Context {
         self.output_mode = OutputMode::TerminalFixedWidth;
     }
 
+    /// Set the number of significant digits that approximate results (e.g.
+    /// of trigonometric functions, logarithms or irrational roots) are
+    /// computed to. The default is 20 digits, and values are clamped to
+    /// lie between 1 and 1000.
+    pub fn set_precision(&mut self, significant_digits: u32) {
+        self.precision = num::Precision::from_digits(significant_digits);
+    }
+
+    /// Set how many decimal places approximate results are shown with when
+    /// no other format (like `to 5 dp`) is requested. The default is 10.
+    pub fn set_default_decimal_places(&mut self, decimal_places: usize) {
+        self.default_decimal_places = decimal_places;
+    }
+
+    /// Set how results are rounded to the number of digits they are shown
+    /// with. The default is [`RoundingMode::HalfEven`]; use
+    /// [`RoundingMode::TowardZero`] to cut off extra digits instead.
+    pub fn set_rounding_mode(&mut self, mode: RoundingMode) {
+        self.rounding = mode;
+    }
+
+    /// Set how many digits after the point are searched for recurring
+    /// digits (e.g. with `to float`). Values whose period is longer are
+    /// shown truncated and marked as approximate. The default is 10000.
+    pub fn set_recurring_digit_limit(&mut self, digits: usize) {
+        self.recurring_digit_limit = digits;
+    }
+
+    /// Set whether to print the `0` before the decimal point of values
+    /// between -1 and 1, e.g. `0.5` or `.5`. The default is to print it.
+    /// Numbers with a base prefix like `0x` always keep it.
+    pub fn set_leading_zero(&mut self, leading_zero: bool) {
+        self.leading_zero = leading_zero;
+    }
+
+    /// Set whether decimals are printed with zeros after the last non-zero
+    /// digit, e.g. `1.25` or `1.2500` with `to 4 dp`. The default is
+    /// [`TrailingZeros::Strip`].
+    pub fn set_trailing_zeros(&mut self, trailing_zeros: TrailingZeros) {
+        self.trailing_zeros = trailing_zeros;
+    }
+
     fn serialize_variables_internal(&self, write: &mut impl io::Write) -> Result<(), FendError> {
         serialize_usize(self.variables.len(), write)?;
         for (k, v) in &self.variables {
@@ -7,10 +7,16 @@ mod complex;
 mod dist;
 mod exact;
 mod formatting_style;
+mod precision;
 mod real;
+mod rounding_mode;
+mod trailing_zeros;
 mod unit;
 
 pub(crate) use formatting_style::FormattingStyle;
+pub(crate) use precision::Precision;
+pub use rounding_mode::RoundingMode;
+pub use trailing_zeros::TrailingZeros;
 
 use crate::error::FendError;
 
@@ -1,9 +1,12 @@
 use crate::error::{FendError, Interrupt};
 use crate::format::Format;
-use crate::interrupt::test_int;
+use crate::interrupt::{report_progress, test_int, test_int_every};
 use crate::num::biguint::BigUint;
-use crate::num::{Base, Exact, FormattingStyle, Range, RangeBound};
-use std::{cmp, fmt, hash, io, ops};
+use crate::num::{
+    Base, Exact, FormattingStyle, Precision, Range, RangeBound, RoundingMode, TrailingZeros,
+};
+use std::cell::RefCell;
+use std::{cmp, fmt, hash, io, iter, ops};
 
 mod sign {
     use crate::{
@@ -61,11 +64,88 @@ use super::biguint::{self, FormattedBigUint};
 use super::out_of_range;
 use sign::Sign;
 
+thread_local! {
+    static BERNOULLI_CACHE: RefCell<Vec<BigRat>> = const { RefCell::new(Vec::new()) };
+}
+
+const BERNOULLI_CACHE_MAX_ENTRIES: usize = 2048;
+
+/// `zeta` only uses the exact formula for even integers up to this value,
+/// since the Bernoulli numbers get slow to compute for larger ones
+const ZETA_EVEN_MAX: usize = 64;
+
+/// `erfc(x)` is treated as 0 once `x^2` exceeds this value, i.e. once the
+/// result drops below roughly `10^-28000`
+const ERFC_MAX_EXPONENT: u64 = 1 << 16;
+
+/// Returns the Bernoulli number `B_n` as an exact fraction (using the
+/// convention `B_1 == -1/2`). Previously computed values are cached, since
+/// each one depends on all of the lower ones.
+pub(crate) fn bernoulli<I: Interrupt>(n: usize, int: &I) -> Result<BigRat, FendError> {
+    if n > 1 && n % 2 == 1 {
+        return Ok(BigRat::from(0));
+    }
+    if let Some(res) = BERNOULLI_CACHE.with(|cache| cache.borrow().get(n).cloned()) {
+        return Ok(res);
+    }
+    let mut numbers = BERNOULLI_CACHE.with(|cache| cache.borrow().clone());
+    if numbers.is_empty() {
+        numbers.push(BigRat::from(1));
+    }
+    while numbers.len() <= n {
+        BigRat::push_bernoulli(&mut numbers, int)?;
+    }
+    let res = numbers[n].clone();
+    numbers.truncate(BERNOULLI_CACHE_MAX_ENTRIES);
+    BERNOULLI_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if numbers.len() > cache.len() {
+            *cache = numbers;
+        }
+    });
+    Ok(res)
+}
+
+/// Returns the rational `c` such that `zeta(n) == c * pi^n`, or `None` if
+/// `n` is odd (in which case no such formula is known)
+pub(crate) fn zeta_even<I: Interrupt>(n: usize, int: &I) -> Result<Option<BigRat>, FendError> {
+    if n % 2 == 1 {
+        return Ok(None);
+    }
+    // zeta(2k) == (-1)^(k+1) B_2k (2 pi)^(2k) / (2 (2k)!)
+    let n_u64 = n as u64;
+    let numerator = bernoulli(n, int)?.abs().mul(
+        &BigRat::from(BigUint::from(1).lshift_n(&n_u64.into(), int)?),
+        int,
+    )?;
+    let denominator = BigRat::from(2).mul(&BigRat::from(n_u64).factorial(int)?, int)?;
+    let res = numerator.div(&denominator, int)?.simplify(int)?;
+    Ok(Some(if n == 0 { -res } else { res }))
+}
+
+/// `pow` rejects results whose numerator or denominator would need more bits
+/// than this (about 80 million decimal digits), rather than running out of
+/// memory partway through
+const DEFAULT_MAX_POW_BITS: u64 = 1 << 28;
+
+/// `factorial` rejects results with more decimal digits than this, which is
+/// the same size limit as for `pow`
+const DEFAULT_MAX_FACTORIAL_DIGITS: u64 = DEFAULT_MAX_POW_BITS * 30103 / 100_000;
+
+/// `to tally` refuses to print more marks than this
+const TALLY_MAX_MARKS: usize = 1000;
+
+/// Every `TALLY_GROUP_SIZE`th tally mark is a `/` that closes a group
+const TALLY_GROUP_SIZE: usize = 5;
+
 #[derive(Clone)]
 pub(crate) struct BigRat {
     sign: Sign,
     num: BigUint,
     den: BigUint,
+    // set by `simplify` so that reducing the same value again is a no-op.
+    // Anything that creates a new numerator or denominator must clear it.
+    reduced: bool,
 }
 
 impl fmt::Debug for BigRat {
@@ -84,14 +164,7 @@ impl fmt::Debug for BigRat {
 impl Ord for BigRat {
     fn cmp(&self, other: &Self) -> cmp::Ordering {
         let int = &crate::interrupt::Never::default();
-        let diff = self.clone().add(-other.clone(), int).unwrap();
-        if diff.num == 0.into() {
-            cmp::Ordering::Equal
-        } else if diff.sign == Sign::Positive {
-            cmp::Ordering::Greater
-        } else {
-            cmp::Ordering::Less
-        }
+        self.clone().add(-other.clone(), int).unwrap().cmp_zero()
     }
 }
 
@@ -133,11 +206,12 @@ impl BigRat {
             sign: Sign::deserialize(read)?,
             num: BigUint::deserialize(read)?,
             den: BigUint::deserialize(read)?,
+            reduced: false,
         })
     }
 
     pub(crate) fn try_as_usize<I: Interrupt>(mut self, int: &I) -> Result<usize, FendError> {
-        if self.sign == Sign::Negative && self.num != 0.into() {
+        if self.is_sign_negative() {
             return Err(FendError::NegativeNumbersNotAllowed);
         }
         self = self.simplify(int)?;
@@ -147,90 +221,276 @@ impl BigRat {
         self.num.try_as_usize(int)
     }
 
-    pub(crate) fn into_f64<I: Interrupt>(mut self, int: &I) -> Result<f64, FendError> {
-        self = self.simplify(int)?;
-        let positive_result = self.num.as_f64() / self.den.as_f64();
-        if self.sign == Sign::Positive {
-            Ok(positive_result)
-        } else {
-            Ok(-positive_result)
-        }
+    /// Converts to the nearest `f64`, rounding halfway cases to even
+    pub(crate) fn into_f64<I: Interrupt>(self, int: &I) -> Result<f64, FendError> {
+        self.into_f64_with_rounding(RoundingMode::HalfEven, int)
+    }
+
+    /// Converts to an `f64` using the given rounding mode. Values too large
+    /// for an `f64` become infinite, unless the rounding mode rounds them
+    /// towards zero.
+    pub(crate) fn into_f64_with_rounding<I: Interrupt>(
+        self,
+        mode: RoundingMode,
+        int: &I,
+    ) -> Result<f64, FendError> {
+        Ok(f64::from_bits(self.into_float_bits(53, 11, mode, int)?))
     }
 
-    #[allow(
-        clippy::cast_possible_truncation,
-        clippy::cast_sign_loss,
-        clippy::cast_precision_loss
-    )]
-    pub(crate) fn from_f64<I: Interrupt>(mut f: f64, int: &I) -> Result<Self, FendError> {
-        let negative = f < 0.0;
-        if negative {
-            f = -f;
+    /// Returns the bit pattern of the IEEE 754 binary float with the given
+    /// significand width (including the implicit bit) and exponent width
+    /// that `self` rounds to
+    fn into_float_bits<I: Interrupt>(
+        self,
+        significand_bits: u32,
+        exponent_bits: u32,
+        mode: RoundingMode,
+        int: &I,
+    ) -> Result<u64, FendError> {
+        let sign_bit = if self.sign == Sign::Negative {
+            1 << (significand_bits + exponent_bits - 1)
+        } else {
+            0
+        };
+        if self.num == 0.into() {
+            return Ok(sign_bit);
         }
-        let i = (f * u64::MAX as f64) as u128;
-        let part1 = i as u64;
-        let part2 = (i >> 64) as u64;
-        Ok(Self {
-            sign: if negative {
-                Sign::Negative
+        let infinity = ((1 << exponent_bits) - 1) << (significand_bits - 1);
+        // exponent of the smallest subnormal number
+        let min_exponent = 3 - (1_i64 << (exponent_bits - 1)) - i64::from(significand_bits);
+        let negative = self.sign == Sign::Negative;
+        // overflowing values saturate to the largest finite value (which is
+        // one below infinity) when rounding towards zero
+        let max_bits = if mode.is_toward_zero(negative) {
+            infinity - 1
+        } else {
+            infinity
+        };
+        // return early for values far outside the range, rather than
+        // shifting them by a huge number of bits
+        let magnitude = self.magnitude_bits();
+        if magnitude > 1_i64 << (exponent_bits - 1) {
+            return Ok(sign_bit | max_bits);
+        }
+        if magnitude + 2 <= min_exponent {
+            // less than half of the smallest subnormal number
+            let round_up = mode.rounds_up(negative, false, false, cmp::Ordering::Less);
+            return Ok(sign_bit | u64::from(round_up));
+        }
+        // find m and e such that self ~= m * 2^e, where m has exactly
+        // `significand_bits` bits (or fewer for subnormal numbers)
+        let mut exponent = (magnitude - i64::from(significand_bits)).max(min_exponent);
+        let (significand, twice_remainder, den) = loop {
+            test_int(int)?;
+            let shift = BigUint::from(exponent.unsigned_abs());
+            let (num, den) = if exponent <= 0 {
+                (self.num.clone().lshift_n(&shift, int)?, self.den.clone())
             } else {
-                Sign::Positive
-            },
-            num: BigUint::from(part1)
-                .add(&BigUint::from(part2).mul(&BigUint::from(u64::MAX), int)?),
-            den: BigUint::from(u64::MAX),
-        })
+                (self.num.clone(), self.den.clone().lshift_n(&shift, int)?)
+            };
+            let (quotient, remainder) = num.divmod(&den, int)?;
+            if quotient.bits() > u64::from(significand_bits) {
+                exponent += 1;
+                continue;
+            }
+            break (quotient.low_u64(), remainder.mul(&2.into(), int)?, den);
+        };
+        let round_up = mode.rounds_up(
+            negative,
+            significand % 2 == 1,
+            twice_remainder == 0.into(),
+            twice_remainder.cmp(&den),
+        );
+        let significand = significand + u64::from(round_up);
+        if exponent - min_exponent >= i64::from(1_u32 << exponent_bits) {
+            return Ok(sign_bit | max_bits);
+        }
+        // Carries from rounding and the implicit leading bit of normal
+        // numbers both end up in the exponent field this way.
+        let bits =
+            ((exponent - min_exponent).unsigned_abs() << (significand_bits - 1)) + significand;
+        Ok(sign_bit | bits.min(max_bits))
     }
 
     // sin works for all real numbers
-    pub(crate) fn sin<I: Interrupt>(self, int: &I) -> Result<Exact<Self>, FendError> {
-        Ok(if self == 0.into() {
-            Exact::new(Self::from(0), true)
-        } else {
-            Exact::new(Self::from_f64(f64::sin(self.into_f64(int)?), int)?, false)
-        })
+    pub(crate) fn sin<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        if self.is_zero() {
+            return Ok(Exact::new(Self::from(0), true));
+        }
+        let bits = prec.bits() + self.guard_bits();
+        let res = self.sin_internal(bits, int)?;
+        Ok(Exact::new(res.round_to_precision(prec, int)?, false))
+    }
+
+    pub(crate) fn cos<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        if self.is_zero() {
+            return Ok(Exact::new(Self::from(1), true));
+        }
+        let res = self.cos_internal(prec.bits(), int)?;
+        Ok(Exact::new(res.round_to_precision(prec, int)?, false))
+    }
+
+    /// Multiplies by pi, which is computed with enough bits for the result
+    /// to have the given precision
+    pub(crate) fn mul_pi<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        if self.is_zero() {
+            return Ok(self);
+        }
+        let bits = prec.bits() + self.magnitude_bits().max(0).unsigned_abs();
+        self.mul(&Self::pi_internal(bits, int)?, int)
     }
 
     // asin, acos and atan only work for values between -1 and 1
-    pub(crate) fn asin<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
+    pub(crate) fn asin<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
         let one = Self::from(1);
         if self > one || self < -one {
             return Err(out_of_range(self.fm(int)?, Range::open(-1, 1)));
         }
-        Self::from_f64(f64::asin(self.into_f64(int)?), int)
+        if self.is_zero() {
+            return Ok(Exact::new(self, true));
+        }
+        let bits = prec.bits() + self.guard_bits();
+        let res = self.asin_internal(bits, int)?;
+        Ok(Exact::new(res.round_to_precision(prec, int)?, false))
     }
 
-    pub(crate) fn acos<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
+    pub(crate) fn acos<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
         let one = Self::from(1);
-        if self > one || self < -one {
+        if self > one || self < -one.clone() {
             return Err(out_of_range(self.fm(int)?, Range::open(-1, 1)));
         }
-        Self::from_f64(f64::acos(self.into_f64(int)?), int)
+        if self == one {
+            return Ok(Exact::new(Self::from(0), true));
+        }
+        // acos(x) == pi/2 - asin(x)
+        let bits = prec.bits() + 8;
+        let half_pi = Self::pi_internal(bits, int)?.div(&2.into(), int)?;
+        let res = half_pi.add(-self.asin_internal(bits, int)?, int)?;
+        Ok(Exact::new(res.round_to_precision(prec, int)?, false))
     }
 
     // note that this works for any real number, unlike asin and acos
-    pub(crate) fn atan<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Self::from_f64(f64::atan(self.into_f64(int)?), int)
+    pub(crate) fn atan<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        if self.is_zero() {
+            return Ok(Exact::new(self, true));
+        }
+        let bits = prec.bits() + self.guard_bits();
+        let res = self.atan_internal(bits, int)?;
+        Ok(Exact::new(res.round_to_precision(prec, int)?, false))
     }
 
-    pub(crate) fn sinh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Self::from_f64(f64::sinh(self.into_f64(int)?), int)
+    pub(crate) fn sinh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        if self.is_zero() {
+            return Ok(Exact::new(self, true));
+        }
+        // sinh(x) == (e^x - e^-x) / 2
+        let bits = prec.bits() + self.guard_bits();
+        let sign = self.sign;
+        let exp = self.abs().exp_internal(bits, int)?;
+        let inv = Self::from(1).div(&exp, int)?;
+        let res = exp.add(-inv, int)?.div(&2.into(), int)?;
+        let res = Self {
+            sign,
+            ..res.round_to_precision(prec, int)?
+        };
+        Ok(Exact::new(res, false))
     }
 
-    pub(crate) fn cosh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Self::from_f64(f64::cosh(self.into_f64(int)?), int)
+    pub(crate) fn cosh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        if self.is_zero() {
+            return Ok(Exact::new(Self::from(1), true));
+        }
+        // cosh(x) == (e^x + e^-x) / 2
+        let exp = self.abs().exp_internal(prec.bits(), int)?;
+        let inv = Self::from(1).div(&exp, int)?;
+        let res = exp.add(inv, int)?.div(&2.into(), int)?;
+        Ok(Exact::new(res.round_to_precision(prec, int)?, false))
     }
 
-    pub(crate) fn tanh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Self::from_f64(f64::tanh(self.into_f64(int)?), int)
+    pub(crate) fn tanh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        if self.is_zero() {
+            return Ok(Exact::new(self, true));
+        }
+        let bits = prec.bits() + self.guard_bits();
+        let sign = self.sign;
+        let x = self.abs();
+        let res = if x.magnitude_bits() > i64::from(64 - bits.leading_zeros()) {
+            // x > bits, so |tanh(x)| is indistinguishable from 1
+            Self::from(1)
+        } else {
+            // tanh(x) == (e^2x - 1) / (e^2x + 1)
+            let exp = x.mul(&2.into(), int)?.exp_internal(bits, int)?;
+            exp.clone()
+                .add(-Self::from(1), int)?
+                .div(&exp.add(1.into(), int)?, int)?
+                .round_to_precision(prec, int)?
+        };
+        Ok(Exact::new(Self { sign, ..res }, false))
     }
 
-    pub(crate) fn asinh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Self::from_f64(f64::asinh(self.into_f64(int)?), int)
+    pub(crate) fn asinh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        if self.is_zero() {
+            return Ok(Exact::new(self, true));
+        }
+        // asinh(x) == ln(x + sqrt(x^2 + 1)), and asinh is odd
+        let bits = prec.bits() + self.guard_bits() + 8;
+        let sign = self.sign;
+        let x = self.abs();
+        let root = x
+            .clone()
+            .mul(&x, int)?
+            .add(1.into(), int)?
+            .root_n_internal(&2.into(), bits, int)?;
+        let res = x.add(root, int)?.ln_internal(bits, int)?;
+        let res = Self {
+            sign,
+            ..res.round_to_precision(prec, int)?
+        };
+        Ok(Exact::new(res, false))
     }
 
     // value must not be less than 1
-    pub(crate) fn acosh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
+    pub(crate) fn acosh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
         if self < 1.into() {
             return Err(out_of_range(
                 self.fm(int)?,
@@ -240,21 +500,56 @@ impl BigRat {
                 },
             ));
         }
-        Self::from_f64(f64::acosh(self.into_f64(int)?), int)
+        if self == 1.into() {
+            return Ok(Exact::new(Self::from(0), true));
+        }
+        // acosh(x) == ln(x + sqrt(x^2 - 1)), which is small when x is close
+        // to 1
+        let bits = prec.bits() + self.clone().add(-Self::from(1), int)?.guard_bits() + 8;
+        let root = self
+            .clone()
+            .mul(&self, int)?
+            .add(-Self::from(1), int)?
+            .root_n_internal(&2.into(), bits, int)?;
+        let res = self.add(root, int)?.ln_internal(bits, int)?;
+        Ok(Exact::new(res.round_to_precision(prec, int)?, false))
     }
 
     // value must be between -1 and 1.
-    pub(crate) fn atanh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
+    pub(crate) fn atanh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
         let one: Self = 1.into();
         if self >= one || self <= -one {
             return Err(out_of_range(self.fm(int)?, Range::open(-1, 1)));
         }
-        Self::from_f64(f64::atanh(self.into_f64(int)?), int)
+        if self.is_zero() {
+            return Ok(Exact::new(self, true));
+        }
+        // atanh(x) == ln((1 + x) / (1 - x)) / 2, and atanh is odd
+        let bits = prec.bits() + self.guard_bits() + 8;
+        let sign = self.sign;
+        let x = self.abs();
+        let ratio = Self::from(1)
+            .add(x.clone(), int)?
+            .div(&Self::from(1).add(-x, int)?, int)?;
+        let res = ratio.ln_internal(bits, int)?.div(&2.into(), int)?;
+        let res = Self {
+            sign,
+            ..res.round_to_precision(prec, int)?
+        };
+        Ok(Exact::new(res, false))
     }
 
     // For all logs: value must be greater than 0
-    pub(crate) fn ln<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        if self <= 0.into() {
+    pub(crate) fn ln<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        if !self.is_sign_positive() {
             return Err(out_of_range(
                 self.fm(int)?,
                 Range {
@@ -263,24 +558,313 @@ impl BigRat {
                 },
             ));
         }
-        Self::from_f64(f64::ln(self.into_f64(int)?), int)
+        if self == 1.into() {
+            return Ok(Exact::new(Self::from(0), true));
+        }
+        let res = self.ln_internal(prec.bits(), int)?;
+        Ok(Exact::new(res.round_to_precision(prec, int)?, false))
+    }
+
+    pub(crate) fn exp<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        if self.is_zero() {
+            return Ok(Exact::new(Self::from(1), true));
+        }
+        let res = self.exp_internal(prec.bits(), int)?;
+        Ok(Exact::new(res.round_to_precision(prec, int)?, false))
+    }
+
+    pub(crate) fn log2<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        self.log_in_base(2, prec, int)
+    }
+
+    pub(crate) fn log10<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        self.log_in_base(10, prec, int)
+    }
+
+    /// Computes ln(|gamma(self)|), which stays small enough to represent
+    /// even when gamma(self) itself would be enormous
+    pub(crate) fn lgamma<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        let x = self.simplify(int)?;
+        if x.den == 1.into() && (x.num == 0.into() || x.sign == Sign::Negative) {
+            return Err(FendError::GammaPole);
+        }
+        if x == 1.into() || x == 2.into() {
+            return Ok(Exact::new(Self::from(0), true));
+        }
+        let mut bits = prec.bits() + 8;
+        let mut retried = false;
+        loop {
+            let res = x.clone().lgamma_internal(bits, int)?;
+            // results close to zero need more bits after the point
+            let lost_bits = res.guard_bits();
+            if retried || lost_bits == 0 {
+                return Ok(Exact::new(res.round_to_precision(prec, int)?, false));
+            }
+            bits += lost_bits;
+            retried = true;
+        }
+    }
+
+    /// Computes the beta function `gamma(a) gamma(b) / gamma(a + b)`. This
+    /// is exact for positive integers, and otherwise goes through `lgamma`
+    /// so that large arguments don't overflow.
+    pub(crate) fn beta<I: Interrupt>(
+        a: Self,
+        b: Self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        let a = a.simplify(int)?;
+        let b = b.simplify(int)?;
+        let is_pole =
+            |x: &Self| x.den == 1.into() && (x.num == 0.into() || x.sign == Sign::Negative);
+        if is_pole(&a) || is_pole(&b) {
+            return Err(FendError::GammaPole);
+        }
+        let sum = a.clone().add(b.clone(), int)?.simplify(int)?;
+        if is_pole(&sum) {
+            // 1 / gamma(a + b) == 0
+            return Ok(Exact::new(Self::from(0), true));
+        }
+        // exact results for larger integers are huge fractions, which are
+        // too slow to compute
+        if a.den == 1.into() && b.den == 1.into() && sum <= 1000.into() {
+            // beta(a, b) == (a - 1)! (b - 1)! / (a + b - 1)!
+            let one = Self::from(1);
+            let num = a
+                .add(-one.clone(), int)?
+                .factorial(int)?
+                .mul(&b.add(-one.clone(), int)?.factorial(int)?, int)?;
+            let den = sum.add(-one, int)?.factorial(int)?;
+            return Ok(Exact::new(num.div(&den, int)?.simplify(int)?, true));
+        }
+        // gamma(x) is negative when x is in (-1, 0), (-3, -2), (-5, -4), ...
+        let is_negative = |x: &Self| -> Result<bool, FendError> {
+            Ok(x.sign == Sign::Negative && x.num.clone().div(&x.den, int)?.is_even(int)?)
+        };
+        let negative = is_negative(&a)? ^ is_negative(&b)? ^ is_negative(&sum)?;
+        let bits = prec.bits() + 8;
+        let log = a
+            .lgamma_internal(bits, int)?
+            .add(b.lgamma_internal(bits, int)?, int)?
+            .add(-sum.lgamma_internal(bits, int)?, int)?;
+        let underflow = log.is_sign_negative();
+        let res = match log.exp_internal(bits, int) {
+            Ok(res) => res.round_to_precision(prec, int)?,
+            // the result is too small to be represented
+            Err(FendError::ValueTooLarge) if underflow => Self::from(0),
+            Err(e) => return Err(e),
+        };
+        Ok(Exact::new(if negative { -res } else { res }, false))
     }
 
-    pub(crate) fn log2<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        if self <= 0.into() {
+    /// Computes the Riemann zeta function for real arguments greater than 1
+    pub(crate) fn zeta<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        let s = self.simplify(int)?;
+        if s <= 1.into() {
             return Err(out_of_range(
-                self.fm(int)?,
+                s.fm(int)?,
                 Range {
-                    start: RangeBound::Open(0),
+                    start: RangeBound::Open(1),
                     end: RangeBound::None,
                 },
             ));
         }
-        Self::from_f64(f64::log2(self.into_f64(int)?), int)
+        let bits = prec.bits() + 16;
+        if s > bits.into() {
+            // zeta(s) == 1 + 2^-s + 3^-s + ..., where 2^-s is below the precision
+            return Ok(Exact::new(Self::from(1), false));
+        }
+        if s.den == 1.into() {
+            if let Some(n) = s.num.try_as_usize(int).ok().filter(|&n| n <= ZETA_EVEN_MAX) {
+                if let Some(coefficient) = zeta_even(n, int)? {
+                    let n = n as u64;
+                    let work_bits = bits + 64 - u64::from(n.leading_zeros());
+                    let pi = Self::pi_internal(work_bits, int)?;
+                    let res = coefficient.mul(&pi.pow_truncated(n, work_bits, int)?, int)?;
+                    return Ok(Exact::new(res.round_to_precision(prec, int)?, false));
+                }
+            }
+        }
+        let res = s.zeta_internal(bits, int)?;
+        Ok(Exact::new(res.round_to_precision(prec, int)?, false))
+    }
+
+    /// Computes the error function `erf(x) == 2/sqrt(pi) integral_0^x e^(-t^2) dt`
+    pub(crate) fn erf<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        if self.num == 0.into() {
+            return Ok(Exact::new(Self::from(0), true));
+        }
+        if self.sign == Sign::Negative {
+            return Ok((-self).erf(prec, int)?.apply(|x| -x));
+        }
+        // erf(x) is roughly 2x/sqrt(pi) for small x, so use enough bits to
+        // keep the relative error small
+        let bits = prec.bits() + 8 + self.guard_bits();
+        let res = self.erf_internal(bits, int)?;
+        Ok(Exact::new(res.round_to_precision(prec, int)?, false))
+    }
+
+    /// Computes the complementary error function `erfc(x) == 1 - erf(x)`,
+    /// without losing precision for large `x`
+    pub(crate) fn erfc<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        if self.num == 0.into() {
+            return Ok(Exact::new(Self::from(1), true));
+        }
+        let bits = prec.bits() + 8;
+        let res = if self.sign == Sign::Negative {
+            // erfc(-x) == 1 + erf(x)
+            Self::from(1).add((-self).erf_internal(bits, int)?, int)?
+        } else if self.clone().mul(&self, int)? > ERFC_MAX_EXPONENT.into() {
+            // erfc(x) < e^(-x^2), which is too small to be worth computing
+            Self::from(0)
+        } else if self.use_erfc_asymptotic(bits, int)? {
+            self.erfc_asymptotic(bits, int)?
+        } else {
+            // 1 - erf(x) is roughly e^(-x^2), so we lose about
+            // x^2 log2(e) bits to cancellation
+            let x_squared = self.clone().mul(&self, int)?;
+            let lost_bits = x_squared
+                .mul(&3.into(), int)?
+                .div(&2.into(), int)?
+                .trunc(int)?
+                .num
+                .low_u64();
+            Self::from(1).add(-self.erf_series(bits + lost_bits, int)?, int)?
+        };
+        Ok(Exact::new(res.round_to_precision(prec, int)?, false))
+    }
+
+    /// Computes erf(x) for positive `x` with roughly the given number of bits
+    /// after the point
+    fn erf_internal<I: Interrupt>(self, bits: u64, int: &I) -> Result<Self, FendError> {
+        if self.use_erfc_asymptotic(bits, int)? {
+            // erfc(x) < e^(-x^2) < 2^-bits, so this rounds to 1
+            Ok(Self::from(1))
+        } else {
+            self.erf_series(bits, int)
+        }
+    }
+
+    /// The asymptotic expansion of erfc is accurate enough (and the power
+    /// series too slow) once `x^2` exceeds the number of bits required
+    fn use_erfc_asymptotic<I: Interrupt>(&self, bits: u64, int: &I) -> Result<bool, FendError> {
+        Ok(self.clone().mul(self, int)? > bits.into())
+    }
+
+    /// Computes erf(x) for non-negative `x` with roughly the given number of
+    /// bits after the point, using
+    /// `erf(x) == 2/sqrt(pi) e^(-x^2) sum_n 2^n x^(2n+1) / (1 * 3 * ... * (2n+1))`
+    /// which, unlike the Taylor series, has no cancellation
+    fn erf_series<I: Interrupt>(self, bits: u64, int: &I) -> Result<Self, FendError> {
+        let x_squared = self.clone().mul(&self, int)?;
+        // the sum is about e^(x^2), so it needs to be computed to ~1.44 x^2
+        // extra bits
+        let work_bits = bits
+            + x_squared
+                .clone()
+                .mul(&3.into(), int)?
+                .div(&2.into(), int)?
+                .trunc(int)?
+                .num
+                .low_u64()
+            + 8;
+        let two_x_squared = x_squared.clone().mul(&2.into(), int)?;
+        let mut term = self.truncate_bits(work_bits, int)?;
+        let mut sum = term.clone();
+        let mut n: u64 = 1;
+        let max_terms = Self::max_series_terms(work_bits);
+        while n <= max_terms {
+            test_int(int)?;
+            term = term
+                .mul(&two_x_squared, int)?
+                .div(&(2 * n + 1).into(), int)?
+                .truncate_bits(work_bits, int)?;
+            if term.num == 0.into() {
+                break;
+            }
+            sum = sum.add(term.clone(), int)?;
+            n += 1;
+        }
+        let sqrt_pi =
+            Self::pi_internal(work_bits, int)?.root_n_internal(&2.into(), work_bits, int)?;
+        (-x_squared)
+            .exp_internal(work_bits, int)?
+            .mul(&sum, int)?
+            .mul(&2.into(), int)?
+            .div(&sqrt_pi, int)?
+            .truncate_bits(bits, int)
+    }
+
+    /// Computes erfc(x) for large positive `x` to roughly the given number
+    /// of relative bits, using the asymptotic expansion
+    /// `erfc(x) ~= e^(-x^2) / (x sqrt(pi)) sum_n (-1)^n (2n-1)!! / (2x^2)^n`
+    fn erfc_asymptotic<I: Interrupt>(self, bits: u64, int: &I) -> Result<Self, FendError> {
+        let x_squared = self.clone().mul(&self, int)?;
+        let two_x_squared = x_squared.clone().mul(&2.into(), int)?;
+        let work_bits = bits + 8;
+        let mut term = Self::from(1);
+        let mut sum = Self::from(1);
+        let mut n: u64 = 1;
+        let max_terms = Self::max_series_terms(work_bits);
+        while n <= max_terms {
+            test_int(int)?;
+            let next = term
+                .clone()
+                .mul(&(2 * n - 1).into(), int)?
+                .div(&two_x_squared, int)?
+                .truncate_bits(work_bits, int)?;
+            // the series diverges, so stop at the smallest term
+            if next.num == 0.into() || next.clone().abs() >= term.abs() {
+                break;
+            }
+            term = -next;
+            sum = sum.add(term.clone(), int)?;
+            n += 1;
+        }
+        let sqrt_pi =
+            Self::pi_internal(work_bits, int)?.root_n_internal(&2.into(), work_bits, int)?;
+        (-x_squared)
+            .exp_internal(work_bits, int)?
+            .mul(&sum, int)?
+            .div(&self.mul(&sqrt_pi, int)?, int)
     }
 
-    pub(crate) fn log10<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        if self <= 0.into() {
+    fn log_in_base<I: Interrupt>(
+        self,
+        base: u64,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        if !self.is_sign_positive() {
             return Err(out_of_range(
                 self.fm(int)?,
                 Range {
@@ -289,70 +873,852 @@ impl BigRat {
                 },
             ));
         }
-        Self::from_f64(f64::log10(self.into_f64(int)?), int)
+        if let Some(res) = self.exact_log(base, int)? {
+            return Ok(Exact::new(res, true));
+        }
+        let bits = prec.bits() + 8;
+        let ln_base = Self::from(base).ln_internal(bits, int)?;
+        let res = self.ln_internal(bits, int)?.div(&ln_base, int)?;
+        Ok(Exact::new(res.round_to_precision(prec, int)?, false))
     }
 
-    fn apply_uint_op<I: Interrupt, R>(
-        mut self,
-        f: impl FnOnce(BigUint, &I) -> Result<R, FendError>,
-        int: &I,
-    ) -> Result<R, FendError> {
-        self = self.simplify(int)?;
-        if self.den != 1.into() {
-            let n = self.fm(int)?;
-            return Err(FendError::MustBeAnInteger(Box::new(n)));
+    /// Returns `Some(n)` if `self` is exactly `base^n` for some integer `n`
+    fn exact_log<I: Interrupt>(&self, base: u64, int: &I) -> Result<Option<Self>, FendError> {
+        let simplified = self.clone().simplify(int)?;
+        let (power, sign) = if simplified.den == 1.into() {
+            (simplified.num, Sign::Positive)
+        } else if simplified.num == 1.into() {
+            (simplified.den, Sign::Negative)
+        } else {
+            return Ok(None);
+        };
+        let base = BigUint::from(base);
+        let exponent = power.ilog(&base, int)?;
+        if BigUint::pow(&base, &exponent.into(), int)? != power {
+            return Ok(None);
         }
-        if self.sign == Sign::Negative && self.num != 0.into() {
-            return Err(out_of_range(self.fm(int)?, Range::ZERO_OR_GREATER));
+        Ok(Some(Self {
+            sign,
+            num: exponent.into(),
+            den: 1.into(),
+            reduced: false,
+        }))
+    }
+
+    fn abs(self) -> Self {
+        Self {
+            sign: Sign::Positive,
+            ..self
         }
-        f(self.num, int)
     }
 
-    pub(crate) fn factorial<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(self.apply_uint_op(BigUint::factorial, int)?.into())
+    /// Returns `|self|` with the sign of `sign_source`, like `f64::copysign`.
+    /// Unlike with floats there is no negative zero, so zero stays positive
+    /// and a zero `sign_source` counts as positive.
+    pub(crate) fn copysign(self, sign_source: &Self) -> Self {
+        let sign = if sign_source.is_sign_negative() {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        Self { sign, ..self.abs() }.normalize_zero_sign()
     }
 
-    pub(crate) fn bitwise<I: Interrupt>(
-        self,
-        rhs: Self,
-        op: crate::ast::BitwiseBop,
-        int: &I,
-    ) -> Result<Self, FendError> {
-        use crate::ast::BitwiseBop;
+    /// Returns `m` such that `2^(m-1) < |self| < 2^(m+1)` for non-zero values
+    #[allow(clippy::cast_possible_wrap)]
+    fn magnitude_bits(&self) -> i64 {
+        self.num.bits() as i64 - self.den.bits() as i64
+    }
 
-        Ok(self
-            .apply_uint_op(
-                |lhs, int| {
-                    let rhs = rhs.apply_uint_op(|rhs, _int| Ok(rhs), int)?;
-                    let result = match op {
-                        BitwiseBop::And => lhs.bitwise_and(&rhs),
-                        BitwiseBop::Or => lhs.bitwise_or(&rhs),
-                        BitwiseBop::Xor => lhs.bitwise_xor(&rhs),
-                        BitwiseBop::LeftShift => lhs.lshift_n(&rhs, int)?,
-                        BitwiseBop::RightShift => lhs.rshift_n(&rhs, int)?,
-                    };
-                    Ok(result)
-                },
-                int,
-            )?
-            .into())
+    /// Extra bits needed to keep the relative error small for values
+    /// close to zero
+    fn guard_bits(&self) -> u64 {
+        (-self.magnitude_bits()).max(0).unsigned_abs()
     }
 
-    /// compute a + b
-    fn add_internal<I: Interrupt>(self, rhs: Self, int: &I) -> Result<Self, FendError> {
-        // a + b == -((-a) + (-b))
-        if self.sign == Sign::Negative {
-            return Ok(-((-self).add_internal(-rhs, int)?));
-        }
+    /// Upper bound on the number of terms to sum in a series computed to
+    /// `bits` bits. For arguments in their intended ranges, all series here
+    /// gain at least one bit per term well before this is reached, so it
+    /// only stops slowly-converging inputs from running (almost) forever.
+    fn max_series_terms(bits: u64) -> u64 {
+        bits.saturating_mul(4).saturating_add(64)
+    }
 
-        assert_eq!(self.sign, Sign::Positive);
+    /// Reports the progress of a series that stops once its terms are
+    /// smaller than `2^-bits`
+    fn report_series_progress<I: Interrupt>(term: &Self, bits: u64, int: &I) {
+        report_progress(int, term.guard_bits(), bits);
+    }
 
-        Ok(if self.den == rhs.den {
+    /// Rounds towards zero to the nearest integer
+    pub(crate) fn trunc<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
+        Ok(Self {
+            sign: self.sign,
+            num: self.num.div(&self.den, int)?,
+            den: 1.into(),
+            reduced: false,
+        })
+    }
+
+    /// Returns the fractional part of `self`, which has the same sign as
+    /// `self` (like `f64::fract`)
+    pub(crate) fn fract<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
+        let integer_part = self.clone().trunc(int)?;
+        self.add(-integer_part, int)
+    }
+
+    /// Rounds to an integer using the given rounding mode
+    pub(crate) fn round<I: Interrupt>(
+        self,
+        mode: RoundingMode,
+        int: &I,
+    ) -> Result<Self, FendError> {
+        let (quotient, remainder) = self.num.divmod(&self.den, int)?;
+        let round_up = mode.rounds_up(
+            self.sign == Sign::Negative,
+            !quotient.is_even(int)?,
+            remainder == 0.into(),
+            remainder.mul(&2.into(), int)?.cmp(&self.den),
+        );
+        Ok(Self {
+            sign: self.sign,
+            num: if round_up {
+                quotient.add(&1.into())
+            } else {
+                quotient
+            },
+            den: 1.into(),
+            reduced: false,
+        })
+    }
+
+    /// Rounds to a multiple of `base^-digits`, i.e. to the given number of
+    /// digits after the point (or to a multiple of a power of `base` if
+    /// `digits` is negative)
+    fn round_to_digits<I: Interrupt>(
+        self,
+        base: u64,
+        digits: i64,
+        mode: RoundingMode,
+        int: &I,
+    ) -> Result<Self, FendError> {
+        let scale = Self::from(BigUint::pow(
+            &base.into(),
+            &digits.unsigned_abs().into(),
+            int,
+        )?);
+        if digits >= 0 {
+            self.mul(&scale, int)?.round(mode, int)?.div(&scale, int)
+        } else {
+            self.div(&scale, int)?.round(mode, int)?.mul(&scale, int)
+        }
+    }
+
+    /// Returns `e` such that `base^(e-1) <= |self| < base^e`, i.e. the number
+    /// of digits before the point (which is negative or zero for values
+    /// below 1). `self` must not be zero.
+    fn integer_digits_in_base<I: Interrupt>(&self, base: u64, int: &I) -> Result<i64, FendError> {
+        let base_uint = BigUint::from(base);
+        let num_log =
+            i64::try_from(self.num.ilog(&base_uint, int)?).map_err(|_| FendError::ValueTooLarge)?;
+        let den_log =
+            i64::try_from(self.den.ilog(&base_uint, int)?).map_err(|_| FendError::ValueTooLarge)?;
+        // floor(log(|self|)) is either `estimate` or `estimate - 1`
+        let estimate = num_log - den_log;
+        let abs = self.clone().abs();
+        let power = Self::from(base)
+            .pow(
+                Self::from(estimate.unsigned_abs()),
+                Precision::default(),
+                int,
+            )?
+            .value;
+        let power = if estimate >= 0 {
+            power
+        } else {
+            Self::from(1).div(&power, int)?
+        };
+        Ok(if abs >= power { estimate + 1 } else { estimate })
+    }
+
+    /// Rounds to the given number of significant digits in the given base
+    fn round_to_significant_digits<I: Interrupt>(
+        self,
+        base: u64,
+        digits: usize,
+        mode: RoundingMode,
+        int: &I,
+    ) -> Result<Self, FendError> {
+        if self.num == 0.into() {
+            return Ok(self);
+        }
+        let digits = i64::try_from(digits).map_err(|_| FendError::ValueTooLarge)?;
+        let integer_digits = self.integer_digits_in_base(base, int)?;
+        self.round_to_digits(base, digits - integer_digits, mode, int)
+    }
+
+    /// Rounds towards zero to a multiple of `2^-bits`. This is used to stop
+    /// the numerators and denominators of series terms from growing
+    /// without bound.
+    fn truncate_bits<I: Interrupt>(self, bits: u64, int: &I) -> Result<Self, FendError> {
+        let keep = bits + self.magnitude_bits().unsigned_abs() + 64;
+        let (num, den) = Self::drop_low_bits(self.num, self.den, keep, int)?;
+        let scale = BigUint::from(1).lshift_n(&bits.into(), int)?;
+        Ok(Self {
+            sign: self.sign,
+            num: num.mul(&scale, int)?.div(&den, int)?,
+            den: scale,
+            reduced: false,
+        })
+    }
+
+    /// Shifts `num` and `den` right by the same number of bits, so that the
+    /// smaller of the two keeps at least `keep` bits. This changes their
+    /// ratio by a relative error of less than `2^(1 - keep)`, and makes
+    /// dividing them much faster when they are both very large.
+    fn drop_low_bits<I: Interrupt>(
+        num: BigUint,
+        den: BigUint,
+        keep: u64,
+        int: &I,
+    ) -> Result<(BigUint, BigUint), FendError> {
+        let excess = num.bits().min(den.bits()).saturating_sub(keep);
+        if excess == 0 {
+            return Ok((num, den));
+        }
+        let excess = BigUint::from(excess);
+        Ok((num.rshift_n(&excess, int)?, den.rshift_n(&excess, int)?))
+    }
+
+    /// Rounds to the given number of significant decimal digits (rounding
+    /// half away from zero)
+    pub(crate) fn round_to_precision<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Self, FendError> {
+        if self.num == 0.into() {
+            return Ok(self);
+        }
+        let digits = i64::from(prec.digits());
+        let ten = BigUint::from(10);
+        let lower_bound = BigUint::pow(&ten, &(digits - 1).unsigned_abs().into(), int)?;
+        let upper_bound = lower_bound.clone().mul(&ten, int)?;
+        // log10(2) ~= 0.30103, so this may be off by one in either direction
+        let mut shift = digits - 1 - self.magnitude_bits() * 30103 / 100_000;
+        loop {
+            test_int(int)?;
+            let scale = BigUint::pow(&ten, &shift.unsigned_abs().into(), int)?;
+            let (num, den) = if shift >= 0 {
+                (self.num.clone().mul(&scale, int)?, self.den.clone())
+            } else {
+                (self.num.clone(), self.den.clone().mul(&scale, int)?)
+            };
+            // only the leading bits affect the result
+            let (num, den) = Self::drop_low_bits(num, den, prec.bits() + 64, int)?;
+            // floor(num / den + 1/2)
+            let rounded = num
+                .mul(&2.into(), int)?
+                .add(&den)
+                .div(&den.mul(&2.into(), int)?, int)?;
+            if rounded >= upper_bound {
+                shift -= 1;
+            } else if rounded < lower_bound {
+                shift += 1;
+            } else {
+                return Ok(if shift >= 0 {
+                    Self {
+                        sign: self.sign,
+                        num: rounded,
+                        den: scale,
+                        reduced: false,
+                    }
+                } else {
+                    Self {
+                        sign: self.sign,
+                        num: rounded.mul(&scale, int)?,
+                        den: 1.into(),
+                        reduced: false,
+                    }
+                });
+            }
+        }
+    }
+
+    /// Sums `z - z^3/3 + z^5/5 - ...` (i.e. atan), or `z + z^3/3 + z^5/5 + ...`
+    /// (i.e. atanh) if `hyperbolic` is set. `|z|` must be at most 1/2.
+    fn atan_series<I: Interrupt>(
+        z: Self,
+        hyperbolic: bool,
+        bits: u64,
+        int: &I,
+    ) -> Result<Self, FendError> {
+        let mut power = z.truncate_bits(bits, int)?;
+        let z_squared = power.clone().mul(&power, int)?.truncate_bits(bits, int)?;
+        let mut sum = power.clone();
+        let mut k: u64 = 1;
+        let max_terms = Self::max_series_terms(bits);
+        loop {
+            test_int(int)?;
+            power = power.mul(&z_squared, int)?.truncate_bits(bits, int)?;
+            if power.num == 0.into() || k > max_terms {
+                return Ok(sum);
+            }
+            Self::report_series_progress(&power, bits, int);
+            let term = power
+                .clone()
+                .div(&(2 * k + 1).into(), int)?
+                .truncate_bits(bits, int)?;
+            sum = if !hyperbolic && k % 2 == 1 {
+                sum.add(-term, int)?
+            } else {
+                sum.add(term, int)?
+            };
+            k += 1;
+        }
+    }
+
+    /// Sums the Taylor series of sin (if `sin` is set) or cos. `|x|` should
+    /// be at most pi/4.
+    fn sin_cos_series<I: Interrupt>(
+        x: Self,
+        sin: bool,
+        bits: u64,
+        int: &I,
+    ) -> Result<Self, FendError> {
+        let x = x.truncate_bits(bits, int)?;
+        let x_squared = x.clone().mul(&x, int)?.truncate_bits(bits, int)?;
+        let (mut term, mut n): (Self, u64) = if sin { (x, 1) } else { (1.into(), 0) };
+        let mut sum = term.clone();
+        let max_terms = Self::max_series_terms(bits);
+        loop {
+            test_int(int)?;
+            term = -term
+                .mul(&x_squared, int)?
+                .div(&((n + 1) * (n + 2)).into(), int)?
+                .truncate_bits(bits, int)?;
+            if term.num == 0.into() || n / 2 > max_terms {
+                return Ok(sum);
+            }
+            Self::report_series_progress(&term, bits, int);
+            sum = sum.add(term.clone(), int)?;
+            n += 2;
+        }
+    }
+
+    /// Computes pi using Machin's formula: pi = 16 atan(1/5) - 4 atan(1/239)
+    fn pi_internal<I: Interrupt>(bits: u64, int: &I) -> Result<Self, FendError> {
+        let bits = bits + 8;
+        let a = Self::atan_series(Self::from(1).div(&5.into(), int)?, false, bits, int)?;
+        let b = Self::atan_series(Self::from(1).div(&239.into(), int)?, false, bits, int)?;
+        a.mul(&16.into(), int)?.add(-b.mul(&4.into(), int)?, int)
+    }
+
+    /// Computes sin(self) to roughly the given number of bits, after reducing
+    /// the argument modulo pi/2
+    fn sin_internal<I: Interrupt>(self, bits: u64, int: &I) -> Result<Self, FendError> {
+        self.sin_internal_with_offset(0, bits, int)
+    }
+
+    /// Computes cos(self) to roughly the given number of bits, using the
+    /// same argument reduction as sin
+    fn cos_internal<I: Interrupt>(self, bits: u64, int: &I) -> Result<Self, FendError> {
+        // cos(x) == sin(x + pi/2)
+        self.sin_internal_with_offset(1, bits, int)
+    }
+
+    /// Computes sin(self + `quarter_turns` * pi/2)
+    fn sin_internal_with_offset<I: Interrupt>(
+        self,
+        quarter_turns: usize,
+        bits: u64,
+        int: &I,
+    ) -> Result<Self, FendError> {
+        let mut work_bits = bits + self.magnitude_bits().max(0).unsigned_abs() + 8;
+        let mut retried = false;
+        let (quotient, remainder) = loop {
+            let half_pi = Self::pi_internal(work_bits, int)?.div(&2.into(), int)?;
+            let quotient = self
+                .clone()
+                .div(&half_pi, int)?
+                .round(RoundingMode::HalfEven, int)?;
+            let remainder = self
+                .clone()
+                .add(-quotient.clone().mul(&half_pi, int)?, int)?
+                .truncate_bits(work_bits, int)?;
+            // arguments close to a multiple of pi lose precision during the
+            // reduction, so retry once with enough extra bits
+            let lost_bits = remainder.guard_bits();
+            if retried || remainder.num == 0.into() || lost_bits <= 8 {
+                break (quotient, remainder);
+            }
+            work_bits += lost_bits;
+            retried = true;
+        };
+        let mut quadrant = quotient.num.divmod(&4.into(), int)?.1.try_as_usize(int)?;
+        if quotient.sign == Sign::Negative {
+            quadrant = (4 - quadrant) % 4;
+        }
+        Ok(match (quadrant + quarter_turns) % 4 {
+            0 => Self::sin_cos_series(remainder, true, work_bits, int)?,
+            1 => Self::sin_cos_series(remainder, false, work_bits, int)?,
+            2 => -Self::sin_cos_series(remainder, true, work_bits, int)?,
+            _ => -Self::sin_cos_series(remainder, false, work_bits, int)?,
+        })
+    }
+
+    fn asin_internal<I: Interrupt>(self, bits: u64, int: &I) -> Result<Self, FendError> {
+        let one = Self::from(1);
+        if self == one || self == -one.clone() {
+            // asin(±1) == ±pi/2
+            let half_pi = Self::pi_internal(bits, int)?.div(&2.into(), int)?;
+            return Ok(half_pi.copysign(&self));
+        }
+        // asin(x) == atan(x / sqrt(1 - x^2))
+        let root = one
+            .add(-self.clone().mul(&self, int)?, int)?
+            .root_n_internal(&2.into(), bits, int)?;
+        self.div(&root, int)?.atan_internal(bits, int)
+    }
+
+    fn atan_internal<I: Interrupt>(self, bits: u64, int: &I) -> Result<Self, FendError> {
+        if self.sign == Sign::Negative {
+            return Ok(-(-self).atan_internal(bits, int)?);
+        }
+        if self > 1.into() {
+            // atan(x) == pi/2 - atan(1/x)
+            let half_pi = Self::pi_internal(bits, int)?.div(&2.into(), int)?;
+            let inverse = Self::from(1).div(&self, int)?;
+            return half_pi.add(-inverse.atan_internal(bits, int)?, int);
+        }
+        if self > Self::from(1).div(&2.into(), int)? {
+            // atan(x) == pi/4 + atan((x - 1) / (x + 1))
+            let quarter_pi = Self::pi_internal(bits, int)?.div(&4.into(), int)?;
+            let z = self
+                .clone()
+                .add(-Self::from(1), int)?
+                .div(&self.add(1.into(), int)?, int)?;
+            return quarter_pi.add(Self::atan_series(z, false, bits + 8, int)?, int);
+        }
+        Self::atan_series(self, false, bits + 8, int)
+    }
+
+    /// Computes e^self to roughly the given number of (relative) bits
+    fn exp_internal<I: Interrupt>(self, bits: u64, int: &I) -> Result<Self, FendError> {
+        if self.is_sign_negative() {
+            return Self::from(1).div(&(-self).exp_internal(bits, int)?, int);
+        }
+        let magnitude = self.magnitude_bits();
+        if magnitude > 16 {
+            return self.exp_internal_scaled(bits, int);
+        }
+        // e^x == (e^(x / 2^k))^(2^k), where x / 2^k < 2^-8
+        let k = (magnitude + 8).max(0).unsigned_abs();
+        let work_bits = bits + k + 8;
+        let x = Self {
+            sign: self.sign,
+            num: self.num,
+            den: self.den.lshift_n(&k.into(), int)?,
+            reduced: false,
+        }
+        .truncate_bits(work_bits, int)?;
+        let mut term = Self::from(1);
+        let mut sum = Self::from(1);
+        let mut n: u64 = 1;
+        let max_terms = Self::max_series_terms(work_bits);
+        while n <= max_terms {
+            test_int(int)?;
+            term = term
+                .mul(&x, int)?
+                .div(&n.into(), int)?
+                .truncate_bits(work_bits, int)?;
+            if term.num == 0.into() {
+                break;
+            }
+            Self::report_series_progress(&term, work_bits, int);
+            sum = sum.add(term.clone(), int)?;
+            n += 1;
+        }
+        for _ in 0..k {
+            test_int(int)?;
+            sum = sum.clone().mul(&sum, int)?.truncate_bits(work_bits, int)?;
+        }
+        Ok(sum)
+    }
+
+    /// Computes e^self for large positive values as e^(self - k ln(2)) 2^k,
+    /// so that only the small remainder goes through the series
+    fn exp_internal_scaled<I: Interrupt>(self, bits: u64, int: &I) -> Result<Self, FendError> {
+        let work_bits = bits + self.magnitude_bits().max(0).unsigned_abs() + 8;
+        let ln_2 = Self::from(2).ln_internal(work_bits, int)?;
+        let k = self
+            .clone()
+            .div(&ln_2, int)?
+            .round(RoundingMode::Floor, int)?;
+        if k.num.bits() > 64 || k.num.low_u64() > DEFAULT_MAX_POW_BITS {
+            return Err(FendError::ValueTooLarge);
+        }
+        let remainder = self
+            .add(-k.clone().mul(&ln_2, int)?, int)?
+            .truncate_bits(work_bits, int)?;
+        let res = remainder.exp_internal(bits, int)?;
+        Ok(Self {
+            sign: res.sign,
+            num: res.num.lshift_n(&k.num, int)?,
+            den: res.den,
+            reduced: false,
+        })
+    }
+
+    /// Computes ln(self) to roughly the given number of bits. `self` must be
+    /// positive.
+    fn ln_internal<I: Interrupt>(self, bits: u64, int: &I) -> Result<Self, FendError> {
+        if self < 1.into() {
+            // ln(x) == -ln(1/x)
+            return Ok(-Self::from(1).div(&self, int)?.ln_internal(bits, int)?);
+        }
+        // x == m * 2^e, where 1 <= m < 2
+        let mut exponent = self.magnitude_bits().max(0).unsigned_abs();
+        let mut mantissa = Self {
+            sign: Sign::Positive,
+            num: self.num.clone(),
+            den: self.den.clone().lshift_n(&exponent.into(), int)?,
+            reduced: false,
+        };
+        if mantissa < 1.into() {
+            exponent -= 1;
+            mantissa = Self {
+                sign: Sign::Positive,
+                num: self.num,
+                den: self.den.lshift_n(&exponent.into(), int)?,
+                reduced: false,
+            };
+        }
+        // ln(m) == 2 atanh((m - 1) / (m + 1))
+        let z = mantissa
+            .clone()
+            .add(-Self::from(1), int)?
+            .div(&mantissa.add(1.into(), int)?, int)?;
+        let work_bits = bits + 8 + z.guard_bits() + 64 - u64::from(exponent.leading_zeros());
+        let mut res = Self::atan_series(z, true, work_bits, int)?.mul(&2.into(), int)?;
+        if exponent > 0 {
+            // ln(2) == 2 atanh(1/3)
+            let ln_2 = Self::atan_series(Self::from(1).div(&3.into(), int)?, true, work_bits, int)?
+                .mul(&2.into(), int)?;
+            res = res.add(ln_2.mul(&exponent.into(), int)?, int)?;
+        }
+        Ok(res)
+    }
+
+    /// Computes ln(|gamma(self)|) with roughly the given number of bits after
+    /// the point. `self` must not be zero or a negative integer.
+    fn lgamma_internal<I: Interrupt>(self, bits: u64, int: &I) -> Result<Self, FendError> {
+        if self.sign == Sign::Negative {
+            // gamma(x) gamma(1 - x) == pi / sin(pi x), so
+            // ln|gamma(x)| == ln(pi) - ln|sin(pi x)| - ln|gamma(1 - x)|
+            let pi = Self::pi_internal(bits + 8, int)?;
+            let sin = pi
+                .clone()
+                .mul(&self, int)?
+                .sin_internal(bits + 8, int)?
+                .abs();
+            let reflected = Self::from(1).add(-self, int)?.lgamma_internal(bits, int)?;
+            return pi
+                .ln_internal(bits, int)?
+                .add(-sin.ln_internal(bits, int)?, int)?
+                .add(-reflected, int);
+        }
+        // Stirling's series converges quickly once x is larger than about
+        // bits * ln(2) / (2 pi), so use
+        // ln(gamma(x)) == ln(gamma(x + m)) - ln(x (x + 1) ... (x + m - 1))
+        let work_bits = bits + self.magnitude_bits().max(0).unsigned_abs() + 16;
+        let threshold = Self::from(work_bits / 8 + 8);
+        let mut z = self;
+        let mut product = Self::from(1);
+        while z < threshold {
+            test_int(int)?;
+            product = product.mul(&z, int)?;
+            z = z.add(1.into(), int)?;
+        }
+        // ln(gamma(z)) ~= (z - 1/2) ln(z) - z + ln(2 pi) / 2
+        //     + sum B_2k / (2k (2k - 1) z^(2k - 1))
+        let half = Self::from(1).div(&2.into(), int)?;
+        let two_pi = Self::pi_internal(work_bits, int)?.mul(&2.into(), int)?;
+        let mut res = z
+            .clone()
+            .add(-half.clone(), int)?
+            .mul(&z.clone().ln_internal(work_bits, int)?, int)?
+            .add(-z.clone(), int)?
+            .add(two_pi.ln_internal(work_bits, int)?.mul(&half, int)?, int)?
+            .truncate_bits(work_bits, int)?;
+        let z_squared = z.clone().mul(&z, int)?;
+        let mut power = z.clone();
+        let mut k: u64 = 1;
+        let max_terms = Self::max_series_terms(work_bits);
+        while k <= max_terms {
+            test_int(int)?;
+            let n = usize::try_from(2 * k).map_err(|_| FendError::ValueTooLarge)?;
+            let term = bernoulli(n, int)?
+                .div(&(2 * k * (2 * k - 1)).into(), int)?
+                .div(&power, int)?
+                .truncate_bits(work_bits, int)?;
+            if term.num == 0.into() {
+                break;
+            }
+            res = res.add(term, int)?;
+            power = power.mul(&z_squared, int)?;
+            k += 1;
+        }
+        if product != 1.into() {
+            res = res.add(-product.ln_internal(work_bits, int)?, int)?;
+        }
+        Ok(res)
+    }
+
+    /// Computes `self^exponent` by repeated squaring, truncating
+    /// intermediate results to the given number of bits after the point
+    fn pow_truncated<I: Interrupt>(
+        mut self,
+        mut exponent: u64,
+        bits: u64,
+        int: &I,
+    ) -> Result<Self, FendError> {
+        let mut res = Self::from(1);
+        while exponent > 0 {
+            test_int(int)?;
+            if exponent % 2 == 1 {
+                res = res.mul(&self, int)?.truncate_bits(bits, int)?;
+            }
+            exponent /= 2;
+            if exponent > 0 {
+                self = self.clone().mul(&self, int)?.truncate_bits(bits, int)?;
+            }
+        }
+        Ok(res)
+    }
+
+    /// Computes zeta(self) for `self > 1` with roughly the given number of
+    /// bits after the point, using the Euler-Maclaurin formula:
+    /// `zeta(s) ~= sum_(j<N) j^-s + N^(1-s) / (s - 1) + N^-s / 2
+    ///     + sum_k B_2k / (2k)! s (s + 1) ... (s + 2k - 2) N^(-s-2k+1)`
+    fn zeta_internal<I: Interrupt>(self, bits: u64, int: &I) -> Result<Self, FendError> {
+        let work_bits = bits + 8;
+        // j^-s, which is exact if s is an integer
+        let inverse_power = |j: u64| -> Result<Self, FendError> {
+            if self.den == 1.into() {
+                Self::from(1).div(&Self::from(BigUint::pow(&j.into(), &self.num, int)?), int)
+            } else {
+                (-self
+                    .clone()
+                    .mul(&Self::from(j).ln_internal(work_bits, int)?, int)?)
+                .exp_internal(work_bits, int)
+            }
+        };
+        let n = work_bits / 8 + 10;
+        let mut res = Self::from(0);
+        for j in 1..n {
+            test_int(int)?;
+            res = res.add(inverse_power(j)?.truncate_bits(work_bits, int)?, int)?;
+        }
+        let n_power = inverse_power(n)?.truncate_bits(work_bits, int)?;
+        let one = Self::from(1);
+        let n_rat = Self::from(n);
+        res = res
+            .add(
+                n_power
+                    .clone()
+                    .mul(&n_rat, int)?
+                    .div(&self.clone().add(-one, int)?, int)?,
+                int,
+            )?
+            .add(n_power.clone().div(&2.into(), int)?, int)?;
+        // factor == s (s + 1) ... (s + 2k - 2) / ((2k)! N^(2k-1))
+        let mut factor = self.clone().div(&n_rat.clone().mul(&2.into(), int)?, int)?;
+        let n_squared = n_rat.clone().mul(&n_rat, int)?;
+        let mut k: u64 = 1;
+        let max_terms = Self::max_series_terms(work_bits);
+        while k <= max_terms {
+            test_int(int)?;
+            let index = usize::try_from(2 * k).map_err(|_| FendError::ValueTooLarge)?;
+            let term = bernoulli(index, int)?
+                .mul(&factor, int)?
+                .mul(&n_power, int)?
+                .truncate_bits(work_bits, int)?;
+            if term.num == 0.into() {
+                break;
+            }
+            res = res.add(term, int)?;
+            let growth = self
+                .clone()
+                .add(Self::from(2 * k - 1), int)?
+                .mul(&self.clone().add(Self::from(2 * k), int)?, int)?
+                .div(&Self::from((2 * k + 1) * (2 * k + 2)), int)?;
+            factor = factor
+                .mul(&growth, int)?
+                .div(&n_squared, int)?
+                .truncate_bits(work_bits + 64, int)?;
+            k += 1;
+        }
+        Ok(res)
+    }
+
+    /// Appends the next Bernoulli number to `numbers`, which must contain
+    /// `B_0` to `B_(n-1)`, using `B_n == -1/(n + 1) sum_(j<n) C(n + 1, j) B_j`
+    fn push_bernoulli<I: Interrupt>(numbers: &mut Vec<Self>, int: &I) -> Result<(), FendError> {
+        let n = numbers.len() as u64;
+        if n > 1 && n % 2 == 1 {
+            numbers.push(Self::from(0));
+            return Ok(());
+        }
+        let mut sum = Self::from(0);
+        let mut binomial = Self::from(1);
+        for (j, b) in (0..).zip(numbers.iter()) {
+            test_int(int)?;
+            if b.num != 0.into() {
+                sum = sum.add(binomial.clone().mul(b, int)?, int)?;
+            }
+            binomial = binomial
+                .mul(&(n + 1 - j).into(), int)?
+                .div(&(j + 1).into(), int)?;
+        }
+        let b = -sum.div(&(n + 1).into(), int)?.simplify(int)?;
+        numbers.push(b);
+        Ok(())
+    }
+
+    /// Computes an approximation of the nth root of a non-negative number,
+    /// with roughly the given number of bits after the point
+    fn root_n_internal<I: Interrupt>(
+        self,
+        n: &BigUint,
+        bits: u64,
+        int: &I,
+    ) -> Result<Self, FendError> {
+        let bits = bits + self.guard_bits();
+        // x^(1/n) == (num * den^(n-1) * 2^(n*bits))^(1/n) / (den * 2^bits)
+        let shift = n.clone().mul(&bits.into(), int)?;
+        let scaled = self
+            .num
+            .mul(
+                &BigUint::pow(&self.den, &n.clone().sub(&1.into()), int)?,
+                int,
+            )?
+            .lshift_n(&shift, int)?;
+        Ok(Self {
+            sign: Sign::Positive,
+            num: scaled.root_n(n, int)?.value,
+            den: self.den.lshift_n(&bits.into(), int)?,
+            reduced: false,
+        })
+    }
+
+    fn apply_uint_op<I: Interrupt, R>(
+        mut self,
+        f: impl FnOnce(BigUint, &I) -> Result<R, FendError>,
+        int: &I,
+    ) -> Result<R, FendError> {
+        self = self.simplify(int)?;
+        if self.den != 1.into() {
+            let n = self.fm(int)?;
+            return Err(FendError::MustBeAnInteger(Box::new(n)));
+        }
+        if self.is_sign_negative() {
+            return Err(out_of_range(self.fm(int)?, Range::ZERO_OR_GREATER));
+        }
+        f(self.num, int)
+    }
+
+    pub(crate) fn factorial<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
+        self.try_factorial(DEFAULT_MAX_FACTORIAL_DIGITS, int)?
+            .ok_or(FendError::ValueTooLarge)
+    }
+
+    /// Like `factorial`, but returns `None` instead of computing results with
+    /// more than `max_digits` decimal digits. Stirling's approximation is
+    /// used to bail out early; results close to the limit are computed and
+    /// then checked exactly.
+    pub(crate) fn try_factorial<I: Interrupt>(
+        self,
+        max_digits: u64,
+        int: &I,
+    ) -> Result<Option<Self>, FendError> {
+        self.apply_uint_op(
+            |n, int| {
+                if n.bits() > 64 {
+                    return Ok(None);
+                }
+                let estimate = Self::estimate_factorial_digits(n.low_u64());
+                #[allow(clippy::cast_precision_loss)]
+                let limit = max_digits as f64 * (1.0 + 1e-9) + 1.0;
+                if estimate > limit {
+                    return Ok(None);
+                }
+                let res = n.factorial(int)?;
+                if res.ilog(&10.into(), int)? >= max_digits {
+                    return Ok(None);
+                }
+                Ok(Some(res.into()))
+            },
+            int,
+        )
+    }
+
+    /// Approximates the number of decimal digits in `n!` using Stirling's
+    /// approximation
+    #[allow(clippy::cast_precision_loss)]
+    fn estimate_factorial_digits(n: u64) -> f64 {
+        if n < 2 {
+            return 1.0;
+        }
+        let n = n as f64;
+        let log10_factorial = (n + 0.5) * n.log10() - n * std::f64::consts::LOG10_E
+            + 0.5 * (2.0 * std::f64::consts::PI).log10()
+            + std::f64::consts::LOG10_E / (12.0 * n);
+        log10_factorial.floor() + 1.0
+    }
+
+    pub(crate) fn bitwise<I: Interrupt>(
+        self,
+        rhs: Self,
+        op: crate::ast::BitwiseBop,
+        int: &I,
+    ) -> Result<Self, FendError> {
+        use crate::ast::BitwiseBop;
+
+        Ok(self
+            .apply_uint_op(
+                |lhs, int| {
+                    let rhs = rhs.apply_uint_op(|rhs, _int| Ok(rhs), int)?;
+                    let result = match op {
+                        BitwiseBop::And => lhs.bitwise_and(&rhs),
+                        BitwiseBop::Or => lhs.bitwise_or(&rhs),
+                        BitwiseBop::Xor => lhs.bitwise_xor(&rhs),
+                        BitwiseBop::LeftShift => lhs.lshift_n(&rhs, int)?,
+                        BitwiseBop::RightShift => lhs.rshift_n(&rhs, int)?,
+                    };
+                    Ok(result)
+                },
+                int,
+            )?
+            .into())
+    }
+
+    /// compute a + b
+    fn add_internal<I: Interrupt>(self, rhs: Self, int: &I) -> Result<Self, FendError> {
+        // a + b == -((-a) + (-b))
+        if self.sign == Sign::Negative {
+            return Ok((-((-self).add_internal(-rhs, int)?)).normalize_zero_sign());
+        }
+
+        assert_eq!(self.sign, Sign::Positive);
+
+        let res = if self.den == rhs.den {
             if rhs.sign == Sign::Negative && self.num < rhs.num {
                 Self {
                     sign: Sign::Negative,
                     num: rhs.num.sub(&self.num),
                     den: self.den,
+                    reduced: false,
                 }
             } else {
                 Self {
@@ -363,6 +1729,7 @@ impl BigRat {
                         self.num.sub(&rhs.num)
                     },
                     den: self.den,
+                    reduced: false,
                 }
             }
         } else {
@@ -376,6 +1743,7 @@ impl BigRat {
                     sign: Sign::Negative,
                     num: b.sub(&a),
                     den: new_denominator,
+                    reduced: false,
                 }
             } else {
                 Self {
@@ -386,18 +1754,30 @@ impl BigRat {
                         a.sub(&b)
                     },
                     den: new_denominator,
+                    reduced: false,
                 }
             }
-        })
+        };
+        Ok(res.normalize_zero_sign())
+    }
+
+    /// Makes sure that zero is always positive, so that arithmetic never
+    /// produces a negative zero
+    fn normalize_zero_sign(mut self) -> Self {
+        if self.is_zero() {
+            self.sign = Sign::Positive;
+        }
+        self
     }
 
     fn simplify<I: Interrupt>(mut self, int: &I) -> Result<Self, FendError> {
-        if self.den == 1.into() {
+        if self.reduced || self.den == 1.into() {
             return Ok(self);
         }
         let gcd = BigUint::gcd(self.num.clone(), self.den.clone(), int)?;
         self.num = self.num.div(&gcd, int)?;
         self.den = self.den.div(&gcd, int)?;
+        self.reduced = true;
         Ok(self)
     }
 
@@ -405,14 +1785,43 @@ impl BigRat {
         if rhs.num == 0.into() {
             return Err(FendError::DivideByZero);
         }
+        if self.den == 1.into() && rhs.den == 1.into() {
+            // integer fast path: no need to cross-multiply
+            return Ok(Self {
+                sign: Sign::sign_of_product(self.sign, rhs.sign),
+                num: self.num,
+                den: rhs.num.clone(),
+                reduced: false,
+            }
+            .integer_if_exact()
+            .normalize_zero_sign());
+        }
         Ok(Self {
             sign: Sign::sign_of_product(self.sign, rhs.sign),
             num: self.num.mul(&rhs.den, int)?,
             den: self.den.mul(&rhs.num, int)?,
-        })
+            reduced: false,
+        }
+        .integer_if_exact()
+        .normalize_zero_sign())
     }
 
-    pub(crate) fn modulo<I: Interrupt>(
+    // If this fraction is exactly an integer, e.g. `10/5`, changes the
+    // denominator to 1 so that later integer checks don't need to compute a
+    // gcd. This is only done when both parts fit in a single word, where the
+    // check is cheap.
+    fn integer_if_exact(mut self) -> Self {
+        if self.num.bits() <= 64 && self.den.bits() <= 64 {
+            let (num, den) = (self.num.low_u64(), self.den.low_u64());
+            if num % den == 0 {
+                self.num = (num / den).into();
+                self.den = 1.into();
+            }
+        }
+        self
+    }
+
+    pub(crate) fn modulo<I: Interrupt>(
         mut self,
         mut rhs: Self,
         int: &I,
@@ -422,7 +1831,7 @@ impl BigRat {
         }
         self = self.simplify(int)?;
         rhs = rhs.simplify(int)?;
-        if (self.sign == Sign::Negative && self.num != 0.into())
+        if self.is_sign_negative()
             || rhs.sign == Sign::Negative
             || self.den != 1.into()
             || rhs.den != 1.into()
@@ -433,6 +1842,7 @@ impl BigRat {
             sign: Sign::Positive,
             num: self.num.divmod(&rhs.num, int)?.1,
             den: 1.into(),
+            reduced: false,
         })
     }
 
@@ -445,6 +1855,7 @@ impl BigRat {
             sign: Sign::Positive,
             num: base_as_u64.into(),
             den: 1.into(),
+            reduced: false,
         };
         loop {
             let old_den = x.den.clone();
@@ -457,6 +1868,167 @@ impl BigRat {
         Ok(x.den == 1.into())
     }
 
+    /// Splits a non-negative value into an integer quotient and a remainder,
+    /// i.e. `self == quotient * divisor + remainder` with
+    /// `0 <= remainder < divisor`
+    fn div_rem<I: Interrupt>(self, divisor: u64, int: &I) -> Result<(Self, Self), FendError> {
+        if self.den == 1.into() {
+            // integer fast path: a single `BigUint` division
+            let (quotient, remainder) = self.num.divmod(&divisor.into(), int)?;
+            return Ok((quotient.into(), remainder.into()));
+        }
+        let divisor = Self::from(divisor);
+        let quotient = self.clone().div(&divisor, int)?.trunc(int)?;
+        let remainder = self.add(-quotient.clone().mul(&divisor, int)?, int)?;
+        Ok((quotient, remainder))
+    }
+
+    /// Formats as `mantissa e exponent` with `1 <= |mantissa| < base`. The
+    /// mantissa is printed exactly, so trailing zeros are never added, and
+    /// the exponent is always written in decimal.
+    fn format_scientific<I: Interrupt>(
+        &self,
+        params: &FormatOptions,
+        int: &I,
+    ) -> Result<Exact<FormattedBigRat>, FendError> {
+        let base = u64::from(params.base.base_as_u8());
+        // 'e' would be a digit in larger bases
+        if base > 14 {
+            return Err(FendError::ScientificNotationBase);
+        }
+        let value = self.clone().abs();
+        let exponent = if value.is_zero() {
+            0
+        } else {
+            value.integer_digits_in_base(base, int)? - 1
+        };
+        let scale = Self::from(BigUint::pow(
+            &base.into(),
+            &exponent.unsigned_abs().into(),
+            int,
+        )?);
+        let mantissa = if exponent >= 0 {
+            value.div(&scale, int)?
+        } else {
+            value.mul(&scale, int)?
+        };
+        let formatted_mantissa = mantissa.format(
+            &FormatOptions {
+                style: FormattingStyle::ExactFloat,
+                term: "",
+                use_parens_if_fraction: false,
+                ..*params
+            },
+            int,
+        )?;
+        let sign = if self.is_sign_negative() {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        Ok(Exact::new(
+            FormattedBigRat {
+                sign,
+                ty: FormattedBigRatType::Decimal(
+                    format!("{}e{exponent}", formatted_mantissa.value),
+                    false,
+                    params.term,
+                ),
+                percent: false,
+            },
+            formatted_mantissa.exact,
+        ))
+    }
+
+    /// Formats a non-negative integer as tally marks, e.g. `||||/ ||` for 7.
+    /// Zero has no marks, so it's printed as `0`.
+    fn format_tally<I: Interrupt>(&self, int: &I) -> Result<Exact<FormattedBigRat>, FendError> {
+        let x = self.clone().simplify(int)?;
+        if x.den != 1.into() {
+            return Err(FendError::MustBeAnInteger(Box::new(x.fm(int)?)));
+        }
+        if x.is_sign_negative() {
+            return Err(FendError::NegativeNumbersNotAllowed);
+        }
+        let count = match x.num.try_as_usize(int) {
+            Ok(count) if count <= TALLY_MAX_MARKS => count,
+            _ => {
+                return Err(out_of_range(
+                    x.fm(int)?,
+                    Range {
+                        start: RangeBound::Closed(0),
+                        end: RangeBound::Closed(TALLY_MAX_MARKS),
+                    },
+                ))
+            }
+        };
+        let mut s = String::with_capacity(count + count / TALLY_GROUP_SIZE);
+        for i in 1..=count {
+            if i > 1 && (i - 1) % TALLY_GROUP_SIZE == 0 {
+                s.push(' ');
+            }
+            s.push(if i % TALLY_GROUP_SIZE == 0 { '/' } else { '|' });
+        }
+        if s.is_empty() {
+            // there are no marks to draw
+            s.push('0');
+        }
+        Ok(Exact::new(
+            FormattedBigRat {
+                sign: Sign::Positive,
+                ty: FormattedBigRatType::Decimal(s, false, ""),
+                percent: false,
+            },
+            true,
+        ))
+    }
+
+    /// Formats a non-negative integer in factorial base, e.g. `341010` for 463
+    /// (`3*5! + 4*4! + 1*3! + 0*2! + 1*1! + 0*0!`)
+    fn format_factorial_base<I: Interrupt>(
+        &self,
+        int: &I,
+    ) -> Result<Exact<FormattedBigRat>, FendError> {
+        let x = self.clone().simplify(int)?;
+        if x.den != 1.into() {
+            return Err(FendError::MustBeAnInteger(Box::new(x.fm(int)?)));
+        }
+        if x.is_sign_negative() {
+            return Err(FendError::NegativeNumbersNotAllowed);
+        }
+        // the 0! digit is always zero; the others are found by successive
+        // division by 2, 3, 4, ...
+        let mut digits = vec![0];
+        let mut rest = x;
+        let mut radix = 2;
+        while !rest.is_zero() {
+            test_int(int)?;
+            let (quotient, remainder) = rest.div_rem(radix, int)?;
+            digits.push(remainder.num.low_u64());
+            rest = quotient;
+            radix += 1;
+        }
+        let separator = if digits.iter().any(|&d| d > 9) {
+            ":"
+        } else {
+            ""
+        };
+        let s = digits
+            .iter()
+            .rev()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(separator);
+        Ok(Exact::new(
+            FormattedBigRat {
+                sign: Sign::Positive,
+                ty: FormattedBigRatType::Decimal(s, false, ""),
+                percent: false,
+            },
+            true,
+        ))
+    }
+
     fn format_as_integer<I: Interrupt>(
         num: &BigUint,
         base: Base,
@@ -488,7 +2060,14 @@ impl BigRat {
                 formatted_int.exact,
             )
         };
-        Ok(Exact::new(FormattedBigRat { sign, ty }, exact))
+        Ok(Exact::new(
+            FormattedBigRat {
+                sign,
+                ty,
+                percent: false,
+            },
+            exact,
+        ))
     }
 
     fn format_as_fraction<I: Interrupt>(
@@ -556,20 +2135,23 @@ impl BigRat {
                 )
             };
         Ok(Exact::new(
-            FormattedBigRat { sign, ty },
+            FormattedBigRat {
+                sign,
+                ty,
+                percent: false,
+            },
             formatted_den.exact && prefix_exact && num_exact,
         ))
     }
 
     fn format_as_decimal<I: Interrupt>(
         &self,
-        style: FormattingStyle,
-        base: Base,
+        params: &FormatOptions,
         sign: Sign,
-        term: &'static str,
         mut terminating: impl FnMut() -> Result<bool, FendError>,
         int: &I,
     ) -> Result<Exact<FormattedBigRat>, FendError> {
+        let (style, base, term) = (params.style, params.base, params.term);
         let integer_part = self.clone().num.div(&self.den, int)?;
         let sf_limit = if let FormattingStyle::SignificantFigures(sf) = style {
             Some(sf)
@@ -616,7 +2198,7 @@ impl BigRat {
                 MaxDigitsToPrint::DecimalPlaces(dp)
             }
         } else {
-            MaxDigitsToPrint::DecimalPlaces(10)
+            MaxDigitsToPrint::DecimalPlaces(params.default_decimal_places)
         };
         let print_integer_part = |ignore_minus_if_zero: bool| {
             let sign =
@@ -631,6 +2213,7 @@ impl BigRat {
             sign: Sign::Positive,
             num: integer_part.clone(),
             den: 1.into(),
+            reduced: false,
         };
         let remaining_fraction = self.clone().add(-integer_as_rational, int)?;
         let (sign, formatted_trailing_digits) = Self::format_trailing_digits(
@@ -640,22 +2223,76 @@ impl BigRat {
             num_trailing_digits_to_print,
             terminating,
             print_integer_part,
+            params.recurring_digit_limit,
             int,
         )?;
+        let mut digits = formatted_trailing_digits.value;
+        if !params.leading_zero && integer_part == 0.into() && !base.has_prefix() {
+            // turn e.g. `0.5` into `.5`
+            if let Some(rest) = digits.strip_prefix("0.") {
+                digits = format!(".{rest}");
+            }
+        }
         Ok(Exact::new(
             FormattedBigRat {
                 sign,
                 ty: FormattedBigRatType::Decimal(
-                    formatted_trailing_digits.value,
+                    digits,
                     !term.is_empty() && base.base_as_u8() > 10,
                     term,
                 ),
+                percent: false,
             },
             formatted_integer_part.exact && formatted_trailing_digits.exact,
         ))
     }
 
+    /// Adds zeros after the decimal point as requested by
+    /// `params.trailing_zeros`. The digits themselves never contain
+    /// trailing zeros, since `format_nonrecurring` leaves them out.
+    fn apply_trailing_zeros(
+        mut res: Exact<FormattedBigRat>,
+        params: &FormatOptions,
+        integer: bool,
+    ) -> Exact<FormattedBigRat> {
+        let min_digits = match (params.trailing_zeros, params.style) {
+            // no decimal point is printed when there are no decimal places
+            (TrailingZeros::Strip, _) | (_, FormattingStyle::DecimalPlaces(0)) => return res,
+            (TrailingZeros::Pad, FormattingStyle::DecimalPlaces(dp)) => dp,
+            (_, FormattingStyle::ExactFloat | FormattingStyle::DecimalPlaces(_)) => 1,
+            // integers are still printed without a decimal point by default
+            (_, FormattingStyle::Auto) if !integer => 1,
+            _ => return res,
+        };
+        let mut digits = match &res.value.ty {
+            FormattedBigRatType::Decimal(s, _, _) => s.clone(),
+            FormattedBigRatType::Integer(Some(n), _, _, _) => n.to_string(),
+            _ => return res,
+        };
+        if digits.contains('(') {
+            return res;
+        }
+        let existing = if let Some(point) = digits.find('.') {
+            digits.len() - point - 1
+        } else {
+            digits.push('.');
+            0
+        };
+        digits.extend(iter::repeat_n('0', min_digits.saturating_sub(existing)));
+        res.value.ty = match res.value.ty {
+            FormattedBigRatType::Decimal(_, space, term)
+            | FormattedBigRatType::Integer(_, space, term, _) => {
+                FormattedBigRatType::Decimal(digits, space, term)
+            }
+            ty @ FormattedBigRatType::Fraction(..) => ty,
+        };
+        res
+    }
+
     /// Prints the decimal expansion of num/den, where num < den, in the given base.
+    /// When looking for recurring digits, this gives up after `recurring_digit_limit`
+    /// digits and prints a truncated expansion instead.
+    #[allow(clippy::too_many_arguments)]
     fn format_trailing_digits<I: Interrupt>(
         base: Base,
         numerator: &BigUint,
@@ -663,42 +2300,48 @@ impl BigRat {
         max_digits: MaxDigitsToPrint,
         mut terminating: impl FnMut() -> Result<bool, FendError>,
         print_integer_part: impl Fn(bool) -> Result<(Sign, String), FendError>,
+        recurring_digit_limit: usize,
         int: &I,
     ) -> Result<(Sign, Exact<String>), FendError> {
         let base_as_u64: u64 = base.base_as_u8().into();
         let b: BigUint = base_as_u64.into();
+        let skip_cycle_detection = max_digits != MaxDigitsToPrint::AllDigits || terminating()?;
+        // the number of digits we might have to print, if it is known
+        let max_digit_count = match max_digits {
+            MaxDigitsToPrint::DecimalPlaces(n) | MaxDigitsToPrint::DpButIgnoreLeadingZeroes(n) => {
+                Some(n)
+            }
+            MaxDigitsToPrint::AllDigits => (!skip_cycle_detection).then_some(recurring_digit_limit),
+        };
         let next_digit =
             |i: usize, num: BigUint, base: &BigUint| -> Result<(BigUint, BigUint), NextDigitErr> {
-                test_int(int)?;
+                test_int_every(int, i)?;
+                if let Some(total) = max_digit_count {
+                    report_progress(int, i as u64, total as u64);
+                }
                 if num == 0.into()
                     || max_digits == MaxDigitsToPrint::DecimalPlaces(i)
                     || max_digits == MaxDigitsToPrint::DpButIgnoreLeadingZeroes(i)
+                    || (!skip_cycle_detection && i == recurring_digit_limit)
                 {
                     return Err(NextDigitErr::Terminated);
                 }
                 // digit = base * numerator / denominator
                 // next_numerator = base * numerator - digit * denominator
-                let bnum = num.mul(base, int)?;
-                let digit = bnum.clone().div(denominator, int)?;
-                let next_num = bnum.sub(&digit.clone().mul(denominator, int)?);
-                Ok((next_num, digit))
+                // the digit is less than the base, so repeated subtraction is
+                // much faster than a long division by a large denominator
+                let mut next_num = num.mul(base, int)?;
+                let mut digit = 0_u64;
+                while next_num >= *denominator {
+                    next_num = next_num.sub(denominator);
+                    digit += 1;
+                }
+                Ok((next_num, digit.into()))
             };
         let fold_digits = |mut s: String, digit: BigUint| -> Result<String, FendError> {
-            let digit_str = digit
-                .format(
-                    &biguint::FormatOptions {
-                        base,
-                        write_base_prefix: false,
-                        sf_limit: None,
-                    },
-                    int,
-                )?
-                .value
-                .to_string();
-            s.push_str(digit_str.as_str());
+            s.push(Self::digit_as_char(&digit));
             Ok(s)
         };
-        let skip_cycle_detection = max_digits != MaxDigitsToPrint::AllDigits || terminating()?;
         if skip_cycle_detection {
             let ignore_number_of_leading_zeroes =
                 matches!(max_digits, MaxDigitsToPrint::DpButIgnoreLeadingZeroes(_));
@@ -708,7 +2351,6 @@ impl BigRat {
                 ignore_number_of_leading_zeroes,
                 next_digit,
                 print_integer_part,
-                int,
             );
         }
         match Self::brents_algorithm(
@@ -732,19 +2374,20 @@ impl BigRat {
                 Ok((sign, Exact::new(trailing_digits, true))) // the recurring decimal is exact
             }
             Err(NextDigitErr::Terminated) => {
-                panic!("decimal number terminated unexpectedly");
+                // the number doesn't terminate, so we must have reached the
+                // digit limit before finding the recurring digits
+                Self::format_nonrecurring(numerator, base, false, next_digit, print_integer_part)
             }
             Err(NextDigitErr::Error(e)) => Err(e),
         }
     }
 
-    fn format_nonrecurring<I: Interrupt>(
+    fn format_nonrecurring(
         numerator: &BigUint,
         base: Base,
         ignore_number_of_leading_zeroes: bool,
         mut next_digit: impl FnMut(usize, BigUint, &BigUint) -> Result<(BigUint, BigUint), NextDigitErr>,
         print_integer_part: impl Fn(bool) -> Result<(Sign, String), FendError>,
-        int: &I,
     ) -> Result<(Sign, Exact<String>), FendError> {
         let mut current_numerator = numerator.clone();
         let mut i = 0;
@@ -770,23 +2413,9 @@ impl BigRat {
                             trailing_digits.push_str(&formatted_int);
                             trailing_digits.push('.');
                         }
-                        for _ in 0..trailing_zeroes {
-                            trailing_digits.push('0');
-                        }
+                        trailing_digits.extend(iter::repeat_n('0', trailing_zeroes));
                         trailing_zeroes = 0;
-                        trailing_digits.push_str(
-                            &digit
-                                .format(
-                                    &biguint::FormatOptions {
-                                        base,
-                                        write_base_prefix: false,
-                                        sf_limit: None,
-                                    },
-                                    int,
-                                )?
-                                .value
-                                .to_string(),
-                        );
+                        trailing_digits.push(Self::digit_as_char(&digit));
                         i += 1;
                     }
                 }
@@ -811,6 +2440,12 @@ impl BigRat {
         }
     }
 
+    /// Converts a single digit (which is always less than the base) to a
+    /// char, without going through `BigUint::format`
+    fn digit_as_char(digit: &BigUint) -> char {
+        Base::digit_as_char(digit.low_u64()).unwrap()
+    }
+
     // Brent's cycle detection algorithm (based on pseudocode from Wikipedia)
     // returns (length of cycle, index of first element of cycle, collected result)
     fn brents_algorithm<T: Clone + Eq, R, U, E1: From<E2>, E2>(
@@ -869,19 +2504,33 @@ impl BigRat {
     }
 
     pub(crate) fn pow<I: Interrupt>(
+        self,
+        rhs: Self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        self.try_pow(rhs, DEFAULT_MAX_POW_BITS, prec, int)
+    }
+
+    /// Like `pow`, but fails with `ValueTooLarge` before doing any work if
+    /// the numerator or denominator of the result would need more than
+    /// `max_bits` bits
+    pub(crate) fn try_pow<I: Interrupt>(
         mut self,
         mut rhs: Self,
+        max_bits: u64,
+        prec: Precision,
         int: &I,
     ) -> Result<Exact<Self>, FendError> {
         self = self.simplify(int)?;
         rhs = rhs.simplify(int)?;
-        if self.num != 0.into() && self.sign == Sign::Negative && rhs.den != 1.into() {
+        if self.is_sign_negative() && rhs.den.is_even(int)? {
             return Err(FendError::RootsOfNegativeNumbers);
         }
         if rhs.sign == Sign::Negative {
             // a^-b => 1/a^b
             rhs.sign = Sign::Positive;
-            let inverse_res = self.pow(rhs, int)?;
+            let inverse_res = self.try_pow(rhs, max_bits, prec, int)?;
             return Ok(Exact::new(
                 Self::from(1).div(&inverse_res.value, int)?,
                 inverse_res.exact,
@@ -892,10 +2541,21 @@ impl BigRat {
         } else {
             Sign::Negative
         };
+        if rhs.num.bits() <= 64 {
+            // a^n >= 2^((bits(a) - 1) n), so this never rejects results
+            // that would actually fit
+            let exponent = rhs.num.low_u64();
+            for part in [&self.num, &self.den] {
+                if part.bits().saturating_sub(1).saturating_mul(exponent) > max_bits {
+                    return Err(FendError::ValueTooLarge);
+                }
+            }
+        }
         let pow_res = Self {
             sign: result_sign,
             num: BigUint::pow(&self.num, &rhs.num, int)?,
             den: BigUint::pow(&self.den, &rhs.num, int)?,
+            reduced: false,
         };
         if rhs.den == 1.into() {
             Ok(Exact::new(pow_res, true))
@@ -905,82 +2565,57 @@ impl BigRat {
                     sign: Sign::Positive,
                     num: rhs.den,
                     den: 1.into(),
+                    reduced: false,
                 },
+                prec,
                 int,
             )?)
         }
     }
 
-    /// n must be an integer
-    fn iter_root_n<I: Interrupt>(
-        mut low_bound: Self,
-        val: &Self,
-        n: &Self,
-        int: &I,
-    ) -> Result<Self, FendError> {
-        let mut high_bound = low_bound.clone().add(1.into(), int)?;
-        for _ in 0..30 {
-            let guess = low_bound
-                .clone()
-                .add(high_bound.clone(), int)?
-                .div(&2.into(), int)?;
-            if &guess.clone().pow(n.clone(), int)?.value < val {
-                low_bound = guess;
-            } else {
-                high_bound = guess;
-            }
-        }
-        low_bound.add(high_bound, int)?.div(&2.into(), int)
-    }
-
     // the boolean indicates whether or not the result is exact
     // n must be an integer
-    pub(crate) fn root_n<I: Interrupt>(self, n: &Self, int: &I) -> Result<Exact<Self>, FendError> {
-        if self.num != 0.into() && self.sign == Sign::Negative {
-            return Err(FendError::RootsOfNegativeNumbers);
-        }
+    // odd roots of negative numbers are negative, while even roots of
+    // negative numbers aren't real and return an error
+    pub(crate) fn root_n<I: Interrupt>(
+        self,
+        n: &Self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
         let n = n.clone().simplify(int)?;
         if n.den != 1.into() || n.sign == Sign::Negative {
             return Err(FendError::NonIntegerNegRoots);
         }
+        if self.is_sign_negative() {
+            if n.num.is_even(int)? {
+                return Err(FendError::RootsOfNegativeNumbers);
+            }
+            let res = (-self).root_n(&n, prec, int)?;
+            return Ok(res.apply(|res| -res));
+        }
         let n = &n.num;
         if self.num == 0.into() {
             return Ok(Exact::new(self, true));
         }
-        let num = self.clone().num.root_n(n, int)?;
-        let den = self.clone().den.root_n(n, int)?;
+        let value = self.simplify(int)?;
+        let num = value.num.clone().root_n(n, int)?;
+        let den = value.den.clone().root_n(n, int)?;
         if num.exact && den.exact {
+            // roots of coprime integers are coprime, so this is already in
+            // lowest terms
             return Ok(Exact::new(
                 Self {
                     sign: Sign::Positive,
                     num: num.value,
                     den: den.value,
+                    reduced: true,
                 },
                 true,
             ));
         }
-        // TODO check in which cases this might still be exact
-        let num_rat = if num.exact {
-            Self::from(num.value)
-        } else {
-            Self::iter_root_n(
-                Self::from(num.value),
-                &Self::from(self.num),
-                &Self::from(n.clone()),
-                int,
-            )?
-        };
-        let den_rat = if den.exact {
-            Self::from(den.value)
-        } else {
-            Self::iter_root_n(
-                Self::from(den.value),
-                &Self::from(self.den),
-                &Self::from(n.clone()),
-                int,
-            )?
-        };
-        Ok(Exact::new(num_rat.div(&den_rat, int)?, false))
+        let res = value.root_n_internal(n, prec.bits(), int)?;
+        Ok(Exact::new(res.round_to_precision(prec, int)?, false))
     }
 
     pub(crate) fn mul<I: Interrupt>(self, rhs: &Self, int: &I) -> Result<Self, FendError> {
@@ -988,7 +2623,9 @@ impl BigRat {
             sign: Sign::sign_of_product(self.sign, rhs.sign),
             num: self.num.mul(&rhs.num, int)?,
             den: self.den.mul(&rhs.den, int)?,
-        })
+            reduced: false,
+        }
+        .normalize_zero_sign())
     }
 
     pub(crate) fn add<I: Interrupt>(self, rhs: Self, int: &I) -> Result<Self, FendError> {
@@ -999,6 +2636,35 @@ impl BigRat {
         self.num.is_definitely_zero()
     }
 
+    pub(crate) fn is_zero(&self) -> bool {
+        self.num.is_zero()
+    }
+
+    /// Returns true if `self` is greater than zero. Zero is neither positive
+    /// nor negative, regardless of its sign.
+    pub(crate) fn is_sign_positive(&self) -> bool {
+        self.sign == Sign::Positive && !self.is_zero()
+    }
+
+    /// Returns true if `self` is less than zero. Zero is neither positive
+    /// nor negative, regardless of its sign.
+    pub(crate) fn is_sign_negative(&self) -> bool {
+        self.sign == Sign::Negative && !self.is_zero()
+    }
+
+    /// Compares `self` to zero by only looking at the sign and numerator,
+    /// which is much cheaper than `self.cmp(&0.into())`. Zero compares equal
+    /// regardless of its sign.
+    pub(crate) fn cmp_zero(&self) -> cmp::Ordering {
+        if self.is_zero() {
+            cmp::Ordering::Equal
+        } else if self.sign == Sign::Positive {
+            cmp::Ordering::Greater
+        } else {
+            cmp::Ordering::Less
+        }
+    }
+
     pub(crate) fn is_definitely_one(&self) -> bool {
         self.sign == Sign::Positive && self.num.is_definitely_one() && self.den.is_definitely_one()
     }
@@ -1017,6 +2683,7 @@ impl BigRat {
         n_factorial.div(&n_minus_r_factorial, int)
     }
 }
+
 enum NextDigitErr {
     Error(FendError),
     Terminated,
@@ -1049,12 +2716,47 @@ impl ops::Neg for BigRat {
     }
 }
 
+impl Default for BigRat {
+    /// Returns zero, with a positive sign and a denominator of 1
+    fn default() -> Self {
+        0.into()
+    }
+}
+
+impl iter::Sum for BigRat {
+    fn sum<It: Iterator<Item = Self>>(iter: It) -> Self {
+        let int = &crate::interrupt::Never::default();
+        iter.fold(0.into(), |acc, x| acc.add(x, int).unwrap())
+    }
+}
+
+impl<'a> iter::Sum<&'a Self> for BigRat {
+    fn sum<It: Iterator<Item = &'a Self>>(iter: It) -> Self {
+        iter.cloned().sum()
+    }
+}
+
+impl iter::Product for BigRat {
+    fn product<It: Iterator<Item = Self>>(iter: It) -> Self {
+        let int = &crate::interrupt::Never::default();
+        iter.fold(1.into(), |acc, x| acc.mul(&x, int).unwrap())
+    }
+}
+
+impl<'a> iter::Product<&'a Self> for BigRat {
+    fn product<It: Iterator<Item = &'a Self>>(iter: It) -> Self {
+        let int = &crate::interrupt::Never::default();
+        iter.fold(1.into(), |acc, x| acc.mul(x, int).unwrap())
+    }
+}
+
 impl From<u64> for BigRat {
     fn from(i: u64) -> Self {
         Self {
             sign: Sign::Positive,
             num: i.into(),
             den: 1.into(),
+            reduced: false,
         }
     }
 }
@@ -1065,16 +2767,62 @@ impl From<BigUint> for BigRat {
             sign: Sign::Positive,
             num: n,
             den: BigUint::from(1),
+            reduced: false,
         }
     }
 }
 
-#[derive(Default)]
+#[derive(Clone, Copy)]
 pub(crate) struct FormatOptions {
     pub(crate) base: Base,
     pub(crate) style: FormattingStyle,
     pub(crate) term: &'static str,
     pub(crate) use_parens_if_fraction: bool,
+    /// How to round values that need more decimal places or significant
+    /// figures than the style allows. With `TowardZero`, extra digits are
+    /// simply cut off.
+    pub(crate) rounding: RoundingMode,
+    /// If no recurring digits are found within this many digits, the
+    /// expansion is truncated and marked as approximate instead
+    pub(crate) recurring_digit_limit: usize,
+    /// How many decimal places to show for approximate values when the
+    /// style doesn't say
+    pub(crate) default_decimal_places: usize,
+    /// Whether to print the `0` before the decimal point of values between
+    /// -1 and 1 (e.g. `0.5` vs `.5`). Numbers with a base prefix always
+    /// keep it.
+    pub(crate) leading_zero: bool,
+    pub(crate) trailing_zeros: TrailingZeros,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            base: Base::default(),
+            style: FormattingStyle::default(),
+            term: "",
+            use_parens_if_fraction: false,
+            rounding: RoundingMode::HalfEven,
+            recurring_digit_limit: FormattingStyle::DEFAULT_RECURRING_DIGIT_LIMIT,
+            default_decimal_places: FormattingStyle::DEFAULT_DECIMAL_PLACES,
+            leading_zero: true,
+            trailing_zeros: TrailingZeros::Strip,
+        }
+    }
+}
+
+impl FormatOptions {
+    /// The defaults chosen by the user for this calculation
+    pub(crate) fn from_context(ctx: &crate::Context) -> Self {
+        Self {
+            rounding: ctx.rounding,
+            recurring_digit_limit: ctx.recurring_digit_limit,
+            default_decimal_places: ctx.default_decimal_places,
+            leading_zero: ctx.leading_zero,
+            trailing_zeros: ctx.trailing_zeros,
+            ..Self::default()
+        }
+    }
 }
 
 impl Format for BigRat {
@@ -1084,6 +2832,7 @@ impl Format for BigRat {
     // Formats as an integer if possible, or a terminating float, otherwise as
     // either a fraction or a potentially approximated floating-point number.
     // The result 'exact' field indicates whether the number was exact or not.
+    #[allow(clippy::too_many_lines)]
     fn format<I: Interrupt>(
         &self,
         params: &Self::Params,
@@ -1094,8 +2843,67 @@ impl Format for BigRat {
         let term = params.term;
         let use_parens_if_fraction = params.use_parens_if_fraction;
 
+        match style {
+            FormattingStyle::Percent(sf) => {
+                let mut res = self.clone().mul(&100.into(), int)?.format(
+                    &FormatOptions {
+                        style: FormattingStyle::SignificantFigures(sf),
+                        ..*params
+                    },
+                    int,
+                )?;
+                res.value.percent = true;
+                return Ok(res);
+            }
+            FormattingStyle::Tally => return self.format_tally(int),
+            FormattingStyle::Scientific => return self.format_scientific(params, int),
+            FormattingStyle::FactorialBase => return self.format_factorial_base(int),
+            _ => (),
+        }
+        if params.rounding != RoundingMode::TowardZero {
+            let mode = params.rounding;
+            let base_u64 = u64::from(base.base_as_u8());
+            let rounded = match style {
+                FormattingStyle::DecimalPlaces(dp) => Some(self.clone().round_to_digits(
+                    base_u64,
+                    i64::try_from(dp).map_err(|_| FendError::ValueTooLarge)?,
+                    mode,
+                    int,
+                )?),
+                FormattingStyle::SignificantFigures(sf) => Some(
+                    self.clone()
+                        .round_to_significant_digits(base_u64, sf, mode, int)?,
+                ),
+                // non-terminating values are cut off after the default
+                // number of decimal places
+                FormattingStyle::Auto if !self.terminates_in_base(base, int)? => Some(
+                    self.clone().round_to_digits(
+                        base_u64,
+                        i64::try_from(params.default_decimal_places)
+                            .map_err(|_| FendError::ValueTooLarge)?,
+                        mode,
+                        int,
+                    )?,
+                ),
+                _ => None,
+            };
+            if let Some(rounded) = rounded {
+                // the rounded value fits in the available digits, so it's
+                // only exact if rounding didn't change it
+                let mut res = rounded.format(
+                    &FormatOptions {
+                        rounding: RoundingMode::TowardZero,
+                        ..*params
+                    },
+                    int,
+                )?;
+                res.exact = res.exact && &rounded == self;
+                return Ok(res);
+            }
+        }
+
         let mut x = self.clone().simplify(int)?;
-        let sign = if x.sign == Sign::Positive || x == 0.into() {
+        let sign = if x.cmp_zero().is_ge() {
             Sign::Positive
         } else {
             Sign::Negative
@@ -1109,7 +2917,7 @@ impl Format for BigRat {
             } else {
                 None
             };
-            return Self::format_as_integer(
+            let res = Self::format_as_integer(
                 &x.num,
                 base,
                 sign,
@@ -1117,7 +2925,8 @@ impl Format for BigRat {
                 use_parens_if_fraction,
                 sf_limit,
                 int,
-            );
+            )?;
+            return Ok(Self::apply_trailing_zeros(res, params, true));
         }
 
         let mut terminating_res = None;
@@ -1138,7 +2947,8 @@ impl Format for BigRat {
         }
 
         // not a fraction, will be printed as a decimal
-        x.format_as_decimal(style, base, sign, term, terminating, int)
+        let res = x.format_as_decimal(params, sign, terminating, int)?;
+        Ok(Self::apply_trailing_zeros(res, params, false))
     }
 }
 
@@ -1178,6 +2988,8 @@ pub(crate) struct FormattedBigRat {
     // whether or not to print a minus sign
     sign: Sign,
     ty: FormattedBigRatType,
+    // whether or not to print a trailing percent sign
+    percent: bool,
 }
 
 impl fmt::Display for FormattedBigRat {
@@ -1231,6 +3043,9 @@ impl fmt::Display for FormattedBigRat {
                 write!(f, "{term}")?;
             }
         }
+        if self.percent {
+            write!(f, "%")?;
+        }
         Ok(())
     }
 }
@@ -1238,10 +3053,18 @@ impl fmt::Display for FormattedBigRat {
 #[cfg(test)]
 mod tests {
     use super::sign::Sign;
-    use super::BigRat;
+    use super::{bernoulli, zeta_even, BigRat};
     use crate::error::FendError;
     use crate::num::biguint::BigUint;
-    use std::mem;
+    use crate::num::{Precision, RoundingMode};
+    use std::{cmp::Ordering, mem};
+
+    /// The exact fraction `n/d`
+    fn frac(n: i64, d: u64) -> Result<BigRat, FendError> {
+        let int = &crate::interrupt::Never::default();
+        let res = BigRat::from(n.unsigned_abs()).div(&BigRat::from(d), int)?;
+        Ok(if n < 0 { -res } else { res })
+    }
 
     #[test]
     fn test_bigrat_from() {
@@ -1265,7 +3088,8 @@ mod tests {
             BigRat {
                 sign: Sign::Positive,
                 num: BigUint::from(16),
-                den: BigUint::from(9)
+                den: BigUint::from(9),
+                reduced: false,
             } < BigRat::from(2)
         );
     }
@@ -1276,12 +3100,1048 @@ mod tests {
             BigRat {
                 sign: Sign::Positive,
                 num: BigUint::from(36),
-                den: BigUint::from(49)
+                den: BigUint::from(49),
+                reduced: false,
             } < BigRat {
                 sign: Sign::Positive,
                 num: BigUint::from(3),
-                den: BigUint::from(4)
+                den: BigUint::from(4),
+                reduced: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_copysign() {
+        let two = BigRat::from(2);
+        assert_eq!(two.clone().copysign(&-BigRat::from(5)), -two.clone());
+        assert_eq!((-two.clone()).copysign(&BigRat::from(5)), two);
+        let zero = BigRat::from(0).copysign(&-BigRat::from(1));
+        assert_eq!(zero.sign, Sign::Positive);
+        let three = BigRat::from(3).copysign(&zero);
+        assert_eq!(three, BigRat::from(3));
+        let three = BigRat::from(3).copysign(&-BigRat::from(0));
+        assert_eq!(three, BigRat::from(3));
+    }
+
+    #[test]
+    fn test_sign_predicates() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        let zero = BigRat::from(0);
+        let negative_zero = -BigRat::from(0);
+        for x in [&zero, &negative_zero] {
+            assert!(x.is_zero());
+            assert!(!x.is_sign_positive());
+            assert!(!x.is_sign_negative());
+        }
+        assert_eq!(zero, negative_zero);
+        // a zero numerator with a negative sign, as produced by some
+        // operations before normalization
+        let unnormalized_zero = BigRat {
+            sign: Sign::Negative,
+            num: 0.into(),
+            den: 5.into(),
+            reduced: false,
+        };
+        for x in [&zero, &negative_zero, &unnormalized_zero] {
+            assert_eq!(x.cmp_zero(), Ordering::Equal);
+            assert_eq!(x.cmp(&BigRat::from(0)), Ordering::Equal);
+        }
+        assert_eq!(BigRat::from(3).cmp_zero(), Ordering::Greater);
+        assert_eq!((-BigRat::from(3)).cmp_zero(), Ordering::Less);
+        assert!(BigRat::from(3).is_sign_positive());
+        assert!(!BigRat::from(3).is_sign_negative());
+        assert!((-BigRat::from(3)).is_sign_negative());
+        let difference = BigRat::from(5).add(-BigRat::from(5), int)?;
+        assert!(difference.is_zero());
+        assert!(!difference.is_sign_negative());
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_negative_zero() -> Result<(), FendError> {
+        use super::FormatOptions;
+        use crate::format::Format;
+
+        let int = &crate::interrupt::Never::default();
+        let format = |x: &BigRat| -> Result<String, FendError> {
+            Ok(x.format(&FormatOptions::default(), int)?.value.to_string())
+        };
+        let results = [
+            (-BigRat::from(5)).add(5.into(), int)?,
+            BigRat::from(5).add(-BigRat::from(5), int)?,
+            (-BigRat::from(0)).add(-BigRat::from(0), int)?,
+            (-BigRat::from(3)).mul(&0.into(), int)?,
+            (-BigRat::from(0)).div(&3.into(), int)?,
+            BigRat::from(0).div(&-BigRat::from(3), int)?,
+        ];
+        for x in results {
+            assert_eq!(x.sign, Sign::Positive);
+            assert_eq!(x, 0.into());
+            assert_eq!(format(&x)?, "0");
+        }
+        let negated = -BigRat::from(0);
+        assert_eq!(negated, 0.into());
+        assert_eq!(negated.cmp(&0.into()), Ordering::Equal);
+        assert_eq!(format(&negated)?, "0");
+        Ok(())
+    }
+
+    #[test]
+    fn test_exact_root_is_integer() -> Result<(), FendError> {
+        use super::FormatOptions;
+        use crate::format::Format;
+
+        let int = &crate::interrupt::Never::default();
+        let root = BigRat::from(144).root_n(&2.into(), Precision::default(), int)?;
+        assert!(root.exact);
+        assert_eq!(root.value.den, 1.into());
+        assert_eq!(root.value.num, 12.into());
+        let formatted = root.value.format(&FormatOptions::default(), int)?;
+        assert_eq!(formatted.value.to_string(), "12");
+        assert!(formatted.exact);
+
+        let root =
+            BigRat::from(144)
+                .div(&9.into(), int)?
+                .root_n(&2.into(), Precision::default(), int)?;
+        assert!(root.exact);
+        assert_eq!(root.value.den, 1.into());
+        assert_eq!(root.value.num, 4.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_round() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        let half = |n: u64| BigRat::from(n).div(&BigRat::from(2), int);
+        let round = |x: BigRat| x.round(RoundingMode::HalfUp, int);
+        let round_even = |x: BigRat| x.round(RoundingMode::HalfEven, int);
+        assert_eq!(round(half(5)?)?, BigRat::from(3));
+        assert_eq!(round(-half(5)?)?, -BigRat::from(3));
+        assert_eq!(round(-half(1)?)?, -BigRat::from(1));
+        assert_eq!(round(-half(3)?)?, -BigRat::from(2));
+        assert_eq!(
+            round(BigRat::from(7).div(&BigRat::from(3), int)?)?,
+            2.into()
+        );
+        assert_eq!(
+            round(-BigRat::from(5).div(&BigRat::from(3), int)?)?,
+            -BigRat::from(2)
+        );
+        assert_eq!(round_even(half(5)?)?, BigRat::from(2));
+        assert_eq!(round_even(half(7)?)?, BigRat::from(4));
+        assert_eq!(round_even(-half(5)?)?, -BigRat::from(2));
+        assert_eq!(round_even(-half(3)?)?, -BigRat::from(2));
+        assert_eq!(round_even(-half(1)?)?, BigRat::from(0));
+        assert_eq!(round_even(-BigRat::from(4))?, -BigRat::from(4));
+        assert_eq!(round_even(-half(5)?)?.den, 1.into());
+
+        let x = BigRat::from(7).div(&BigRat::from(3), int)?;
+        let round_with = |x: &BigRat, mode| x.clone().round(mode, int);
+        assert_eq!(round_with(&x, RoundingMode::TowardZero)?, 2.into());
+        assert_eq!(
+            round_with(&-x.clone(), RoundingMode::TowardZero)?,
+            -BigRat::from(2)
+        );
+        assert_eq!(round_with(&x, RoundingMode::Floor)?, 2.into());
+        assert_eq!(
+            round_with(&-x.clone(), RoundingMode::Floor)?,
+            -BigRat::from(3)
+        );
+        assert_eq!(round_with(&x, RoundingMode::Ceil)?, 3.into());
+        assert_eq!(
+            round_with(&-x.clone(), RoundingMode::Ceil)?,
+            -BigRat::from(2)
+        );
+        for mode in [RoundingMode::Floor, RoundingMode::Ceil] {
+            assert_eq!(round_with(&-BigRat::from(4), mode)?, -BigRat::from(4));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_recurring_digit_limit() -> Result<(), FendError> {
+        use super::FormatOptions;
+        use crate::format::Format;
+        use crate::num::FormattingStyle;
+
+        let int = &crate::interrupt::Never::default();
+        let format = |x: &BigRat, recurring_digit_limit| -> Result<(String, bool), FendError> {
+            let options = FormatOptions {
+                style: FormattingStyle::ExactFloat,
+                recurring_digit_limit,
+                ..Default::default()
+            };
+            let res = x.format(&options, int)?;
+            Ok((res.value.to_string(), res.exact))
+        };
+        // 1/97 has a period of 96 digits
+        let x = BigRat::from(1).div(&97.into(), int)?;
+        let (recurring, exact) = format(&x, 1000)?;
+        assert!(recurring.ends_with(')'));
+        assert!(exact);
+        assert_eq!(
+            format(&x, 20)?,
+            ("0.01030927835051546391".to_string(), false)
+        );
+        // terminating expansions aren't affected by the limit
+        let x = BigRat::from(1).div(&1024.into(), int)?;
+        assert_eq!(format(&x, 2)?, ("0.0009765625".to_string(), true));
+        Ok(())
+    }
+
+    #[test]
+    fn test_zero_decimal_places() -> Result<(), FendError> {
+        use super::{FormatOptions, TrailingZeros};
+        use crate::format::Format;
+        use crate::num::FormattingStyle;
+
+        let int = &crate::interrupt::Never::default();
+        let format = |x: &BigRat, options| -> Result<(String, bool), FendError> {
+            let res = x.format(&options, int)?;
+            Ok((res.value.to_string(), res.exact))
+        };
+        let dp = FormatOptions {
+            style: FormattingStyle::DecimalPlaces(0),
+            rounding: RoundingMode::TowardZero,
+            ..Default::default()
+        };
+        let default_dp = FormatOptions {
+            default_decimal_places: 0,
+            ..Default::default()
+        };
+        let half_up = FormatOptions {
+            rounding: RoundingMode::HalfUp,
+            ..dp
+        };
+        let keep_one = FormatOptions {
+            trailing_zeros: TrailingZeros::KeepOne,
+            ..dp
+        };
+        let x = frac(11, 2)?;
+        // without rounding the digits are cut off, otherwise the value is
+        // rounded to an integer
+        assert_eq!(format(&x, dp)?, ("5".to_string(), false));
+        assert_eq!(format(&frac(10, 3)?, default_dp)?, ("3".to_string(), false));
+        assert_eq!(format(&x, half_up)?, ("6".to_string(), false));
+        assert_eq!(format(&x, keep_one)?, ("5".to_string(), false));
+        assert_eq!(format(&-frac(1, 3)?, dp)?, ("0".to_string(), false));
+        assert_eq!(format(&-frac(1, 3)?, default_dp)?, ("0".to_string(), false));
+        assert_eq!(format(&-frac(2, 3)?, half_up)?, ("-1".to_string(), false));
+        assert_eq!(format(&3.into(), dp)?, ("3".to_string(), true));
+        Ok(())
+    }
+
+    #[test]
+    fn test_rounding_carries_in_base() -> Result<(), FendError> {
+        use super::FormatOptions;
+        use crate::format::Format;
+        use crate::num::{Base, FormattingStyle};
+
+        let int = &crate::interrupt::Never::default();
+        let format = |x: &BigRat, base: u8, style| -> Result<(String, bool), FendError> {
+            let options = FormatOptions {
+                base: Base::from_plain_base(base)?,
+                style,
+                rounding: RoundingMode::HalfUp,
+                ..Default::default()
+            };
+            let res = x.format(&options, int)?;
+            Ok((res.value.to_string(), res.exact))
+        };
+        let dp = FormattingStyle::DecimalPlaces;
+        let sf = FormattingStyle::SignificantFigures;
+        // 0.10111 -> 0.110
+        assert_eq!(
+            format(&frac(23, 32)?, 2, dp(3))?,
+            ("0.11".to_string(), false)
+        );
+        // 1.1111 -> 10.00
+        assert_eq!(format(&frac(31, 16)?, 2, dp(2))?, ("10".to_string(), false));
+        // 0.3777 -> 0.40
+        assert_eq!(
+            format(&frac(2047, 4096)?, 8, dp(2))?,
+            ("0.4".to_string(), false)
+        );
+        // 0.7774 -> 1.000
+        assert_eq!(
+            format(&frac(4092, 4096)?, 8, dp(3))?,
+            ("1".to_string(), false)
+        );
+        // 77.7 -> 100
+        assert_eq!(
+            format(&frac(511, 8)?, 8, sf(2))?,
+            ("100".to_string(), false)
+        );
+        // f.ff8 -> 10.00
+        assert_eq!(
+            format(&frac(65528, 4096)?, 16, dp(2))?,
+            ("10".to_string(), false)
+        );
+        // fff -> 1000
+        assert_eq!(
+            format(&4095.into(), 16, sf(2))?,
+            ("1000".to_string(), false)
+        );
+        // 9.f8 -> a.0, since the carry stops at the first digit below f
+        assert_eq!(
+            format(&frac(2552, 256)?, 16, dp(1))?,
+            ("a".to_string(), false)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rounded_formatting() -> Result<(), FendError> {
+        use super::FormatOptions;
+        use crate::format::Format;
+        use crate::num::FormattingStyle;
+
+        let int = &crate::interrupt::Never::default();
+        let format = |x: &BigRat, style, rounding| -> Result<(String, bool), FendError> {
+            let options = FormatOptions {
+                style,
+                rounding,
+                ..Default::default()
+            };
+            let res = x.format(&options, int)?;
+            Ok((res.value.to_string(), res.exact))
+        };
+        let dp = FormattingStyle::DecimalPlaces;
+        let sf = FormattingStyle::SignificantFigures;
+        let two_thirds = frac(2, 3)?;
+        assert_eq!(
+            format(&two_thirds, dp(3), RoundingMode::TowardZero)?,
+            ("0.666".to_string(), false)
+        );
+        assert_eq!(
+            format(&frac(1, 8)?, dp(1), RoundingMode::AwayFromZero)?,
+            ("0.2".to_string(), false)
+        );
+        assert_eq!(
+            format(&-frac(1, 8)?, dp(1), RoundingMode::AwayFromZero)?,
+            ("-0.2".to_string(), false)
+        );
+        let half_even = RoundingMode::HalfEven;
+        assert_eq!(
+            format(&two_thirds, dp(3), half_even)?,
+            ("0.667".to_string(), false)
+        );
+        assert_eq!(
+            format(&-two_thirds.clone(), dp(2), RoundingMode::Ceil)?,
+            ("-0.66".to_string(), false)
+        );
+        assert_eq!(
+            format(&frac(5, 8)?, dp(2), half_even)?,
+            ("0.62".to_string(), false)
+        );
+        assert_eq!(
+            format(&frac(5, 8)?, dp(2), RoundingMode::HalfUp)?,
+            ("0.63".to_string(), false)
+        );
+        assert_eq!(
+            format(&frac(5, 8)?, dp(3), half_even)?,
+            ("0.625".to_string(), true)
+        );
+        assert_eq!(
+            format(&frac(9999, 1000)?, dp(2), half_even)?,
+            ("10".to_string(), false)
+        );
+        assert_eq!(
+            format(&two_thirds, sf(2), half_even)?,
+            ("0.67".to_string(), false)
+        );
+        assert_eq!(
+            format(&frac(12345, 1)?, sf(3), half_even)?,
+            ("12300".to_string(), false)
+        );
+        assert_eq!(
+            format(&frac(12351, 1)?, sf(3), half_even)?,
+            ("12400".to_string(), false)
+        );
+        assert_eq!(
+            format(&frac(1, 800)?, sf(1), RoundingMode::Ceil)?,
+            ("0.002".to_string(), false)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_into_f64_with_rounding() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        let third = BigRat::from(1).div(&BigRat::from(3), int)?;
+        let nearest = third.clone().into_f64(int)?;
+        let down = third
+            .clone()
+            .into_f64_with_rounding(RoundingMode::TowardZero, int)?;
+        let up = third.into_f64_with_rounding(RoundingMode::Ceil, int)?;
+        assert_eq!(nearest.to_bits(), (1.0_f64 / 3.0).to_bits());
+        assert!(down < up);
+        assert_eq!(down.to_bits() + 1, up.to_bits());
+        let huge = BigRat::from(2)
+            .pow(1024.into(), Precision::default(), int)?
+            .value;
+        assert_eq!(
+            huge.clone().into_f64(int)?.to_bits(),
+            f64::INFINITY.to_bits()
+        );
+        assert_eq!(
+            huge.clone()
+                .into_f64_with_rounding(RoundingMode::TowardZero, int)?
+                .to_bits(),
+            f64::MAX.to_bits()
+        );
+        assert_eq!(
+            (-huge.clone())
+                .into_f64_with_rounding(RoundingMode::Floor, int)?
+                .to_bits(),
+            f64::NEG_INFINITY.to_bits()
+        );
+        assert_eq!(
+            (-huge)
+                .into_f64_with_rounding(RoundingMode::Ceil, int)?
+                .to_bits(),
+            f64::MIN.to_bits()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_into_f64_range() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        let pow2 = |n: i64| -> Result<BigRat, FendError> {
+            let power = BigRat::from(2)
+                .pow(n.unsigned_abs().into(), Precision::default(), int)?
+                .value;
+            if n >= 0 {
+                Ok(power)
+            } else {
+                BigRat::from(1).div(&power, int)
             }
+        };
+        let max = BigRat::from((1 << 53) - 1).mul(&pow2(971)?, int)?;
+        let min_positive = pow2(-1022)?;
+        let ten_400 = BigRat::from(10)
+            .pow(400.into(), Precision::default(), int)?
+            .value;
+        // compare bit patterns, which also distinguishes -0.0 from 0.0
+        let bits = |x: BigRat| x.into_f64(int).map(f64::to_bits);
+        assert_eq!(bits(ten_400.clone())?, f64::INFINITY.to_bits());
+        assert_eq!(bits(-ten_400.clone())?, f64::NEG_INFINITY.to_bits());
+        assert_eq!(bits(BigRat::from(1).div(&ten_400, int)?)?, 0);
+        assert_eq!(bits(max.clone())?, f64::MAX.to_bits());
+        assert_eq!(bits(-max.clone())?, f64::MIN.to_bits());
+        // halfway between f64::MAX and 2^1024 rounds to even, i.e. overflows
+        assert_eq!(bits(max.clone().add(pow2(969)?, int)?)?, f64::MAX.to_bits());
+        assert_eq!(
+            bits(max.clone().add(pow2(970)?, int)?)?,
+            f64::INFINITY.to_bits()
+        );
+        assert_eq!(bits(min_positive.clone())?, f64::MIN_POSITIVE.to_bits());
+        // just below the smallest normal number
+        let below = min_positive.clone().add(-pow2(-1080)?, int)?;
+        assert_eq!(bits(below)?, f64::MIN_POSITIVE.to_bits());
+        let largest_subnormal = f64::MIN_POSITIVE.to_bits() - 1;
+        assert_eq!(
+            bits(BigRat::from((1 << 52) - 1).mul(&pow2(-1074)?, int)?)?,
+            largest_subnormal
+        );
+        assert_eq!(bits(pow2(-1074)?)?, 1);
+        // half of the smallest subnormal rounds to even, i.e. zero
+        assert_eq!(bits(pow2(-1075)?)?, 0);
+        assert_eq!(bits(BigRat::from(3).mul(&pow2(-1076)?, int)?)?, 1);
+        assert_eq!(bits(-pow2(-1076)?)?, (-0.0_f64).to_bits());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fract() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        let quarter = BigRat::from(1).div(&BigRat::from(4), int)?;
+        let x = -BigRat::from(9).div(&BigRat::from(4), int)?;
+        assert_eq!(x.clone().trunc(int)?, -BigRat::from(2));
+        assert_eq!(x.fract(int)?, -quarter.clone());
+        assert_eq!(BigRat::from(7).fract(int)?, 0.into());
+        assert_eq!(
+            BigRat::from(7).div(&BigRat::from(3), int)?.fract(int)?,
+            BigRat::from(1).div(&BigRat::from(3), int)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_into_f64_far_out_of_range() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        let huge = BigRat::from(2)
+            .pow(100_000.into(), Precision::default(), int)?
+            .value;
+        let tiny = BigRat::from(1).div(&huge, int)?;
+        assert_eq!(
+            huge.clone().into_f64(int)?.to_bits(),
+            f64::INFINITY.to_bits()
+        );
+        assert_eq!(
+            (-huge).into_f64(int)?.to_bits(),
+            f64::NEG_INFINITY.to_bits()
+        );
+        assert_eq!(tiny.clone().into_f64(int)?.to_bits(), 0);
+        assert_eq!((-tiny).into_f64(int)?.to_bits(), (-0.0_f64).to_bits());
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_factorial() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        // 20! == 2432902008176640000 has 19 digits
+        let twenty_factorial = BigRat::from(2_432_902_008_176_640_000);
+        assert_eq!(
+            BigRat::from(20).try_factorial(19, int)?,
+            Some(twenty_factorial)
+        );
+        assert_eq!(BigRat::from(20).try_factorial(18, int)?, None);
+        assert_eq!(BigRat::from(0).try_factorial(1, int)?, Some(1.into()));
+        assert_eq!(BigRat::from(1_000_000_000).try_factorial(1000, int)?, None);
+        let huge = BigRat::from(u64::MAX).mul(&BigRat::from(u64::MAX), int)?;
+        assert_eq!(huge.try_factorial(u64::MAX, int)?, None);
+        assert!((-BigRat::from(3)).try_factorial(10, int).is_err());
+        for (n, digits) in [(10, 7), (100, 158), (1000, 2568)] {
+            let estimate = BigRat::estimate_factorial_digits(n);
+            assert!((estimate - f64::from(digits)).abs() < 0.5);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_lgamma() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        let prec = Precision::from_digits(15);
+        // ln(4!) == ln(24)
+        assert_eq!(
+            BigRat::from(5).lgamma(prec, int)?.value,
+            frac(317_805_383_034_795, 100_000_000_000_000)?
+        );
+        // ln(sqrt(pi))
+        assert_eq!(
+            frac(1, 2)?.lgamma(prec, int)?.value,
+            frac(572_364_942_924_700, 1_000_000_000_000_000)?
+        );
+        // ln(999!)
+        assert_eq!(
+            BigRat::from(1000).lgamma(prec, int)?.value,
+            frac(590_522_042_320_918, 100_000_000_000)?
+        );
+        // ln|gamma(-1/2)| == ln(2 sqrt(pi))
+        assert_eq!(
+            (-frac(1, 2)?).lgamma(prec, int)?.value,
+            frac(126_551_212_348_465, 100_000_000_000_000)?
+        );
+        assert_eq!(BigRat::from(2).lgamma(prec, int)?.value, 0.into());
+        assert!(BigRat::from(0).lgamma(prec, int).is_err());
+        assert!((-BigRat::from(3)).lgamma(prec, int).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_beta() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        let prec = Precision::from_digits(15);
+        let beta = |a: BigRat, b: BigRat| BigRat::beta(a, b, prec, int);
+        let res = beta(2.into(), 3.into())?;
+        assert!(res.exact);
+        assert_eq!(res.value, frac(1, 12)?);
+        assert_eq!(beta(1.into(), 7.into())?.value, frac(1, 7)?);
+        assert_eq!(beta(5.into(), 5.into())?.value, frac(1, 630)?);
+        // beta(1/2, 1/2) == pi
+        let res = beta(frac(1, 2)?, frac(1, 2)?)?;
+        assert!(!res.exact);
+        assert_eq!(res.value, frac(314_159_265_358_979, 100_000_000_000_000)?);
+        // gamma(-1/2) and gamma(-1/4) are both negative
+        let res = beta(-frac(1, 2)?, frac(1, 4)?)?;
+        assert_eq!(res.value, frac(262_205_755_429_212, 100_000_000_000_000)?);
+        // would overflow without going through lgamma
+        let res = beta(500.into(), frac(1001, 2)?)?;
+        let scale = BigRat::from(10).pow(316.into(), prec, int)?.value;
+        assert_eq!(
+            res.value,
+            BigRat::from(104_631_765_838_669).div(&scale, int)?
+        );
+        assert_eq!(beta(frac(1, 2)?, -frac(1, 2)?)?.value, 0.into());
+        assert!(beta(-BigRat::from(1), 2.into()).is_err());
+        // too small to be represented
+        let huge = BigRat::from(1_000_000_000);
+        let res = beta(huge.clone(), huge.add(frac(1, 2)?, int)?)?;
+        assert!(!res.exact);
+        assert_eq!(res.value, 0.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bernoulli() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        assert_eq!(bernoulli(0, int)?, 1.into());
+        assert_eq!(bernoulli(1, int)?, frac(-1, 2)?);
+        assert_eq!(bernoulli(2, int)?, frac(1, 6)?);
+        assert_eq!(bernoulli(3, int)?, 0.into());
+        assert_eq!(bernoulli(12, int)?, frac(-691, 2730)?);
+        assert_eq!(bernoulli(20, int)?, frac(-174_611, 330)?);
+        // computed from the cached values above
+        assert_eq!(bernoulli(30, int)?, frac(8_615_841_276_005, 14322)?);
+        assert_eq!(bernoulli(4, int)?, frac(-1, 30)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_zeta() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        let prec = Precision::from_digits(15);
+        assert_eq!(zeta_even(2, int)?, Some(frac(1, 6)?));
+        assert_eq!(zeta_even(4, int)?, Some(frac(1, 90)?));
+        assert_eq!(zeta_even(12, int)?, Some(frac(691, 638_512_875)?));
+        assert_eq!(zeta_even(3, int)?, None);
+        let zeta = |x: BigRat| Ok::<_, FendError>(x.zeta(prec, int)?.value);
+        assert_eq!(
+            zeta(2.into())?,
+            frac(164_493_406_684_823, 100_000_000_000_000)?
+        );
+        assert_eq!(
+            zeta(3.into())?,
+            frac(120_205_690_315_959, 100_000_000_000_000)?
+        );
+        assert_eq!(
+            zeta(frac(3, 2)?)?,
+            frac(261_237_534_868_549, 100_000_000_000_000)?
+        );
+        assert_eq!(zeta(50.into())?, 1.into());
+        assert!(zeta(1.into()).is_err());
+        assert!(zeta(frac(1, 2)?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_erf() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        let prec = Precision::from_digits(15);
+        let erf = |x: BigRat| x.erf(prec, int);
+        let erfc = |x: BigRat| x.erfc(prec, int);
+        let res = erf(0.into())?;
+        assert!(res.exact);
+        assert_eq!(res.value, 0.into());
+        let res = erfc(0.into())?;
+        assert!(res.exact);
+        assert_eq!(res.value, 1.into());
+        let res = erf(frac(1, 2)?)?;
+        assert!(!res.exact);
+        assert_eq!(res.value, frac(520_499_877_813_047, 1_000_000_000_000_000)?);
+        assert_eq!(
+            erf(-BigRat::from(2))?.value,
+            -frac(995_322_265_018_953, 1_000_000_000_000_000)?
+        );
+        assert_eq!(
+            erfc(3.into())?.value,
+            frac(220_904_969_985_854, 10_u64.pow(19))?
+        );
+        assert_eq!(
+            erfc(-BigRat::from(1))?.value,
+            frac(184_270_079_294_971, 100_000_000_000_000)?
         );
+        // uses the asymptotic expansion
+        let scale = BigRat::from(10).pow(BigRat::from(59), prec, int)?.value;
+        let expected = BigRat::from(208_848_758_376_254).div(&scale, int)?;
+        assert_eq!(erfc(10.into())?.value, expected);
+        assert_eq!(erf(10.into())?.value, 1.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_percent_formatting() -> Result<(), FendError> {
+        use super::FormatOptions;
+        use crate::format::Format;
+        use crate::num::FormattingStyle;
+
+        let int = &crate::interrupt::Never::default();
+        let percent = |x: BigRat, sf: usize| -> Result<String, FendError> {
+            let options = FormatOptions {
+                style: FormattingStyle::Percent(sf),
+                ..Default::default()
+            };
+            Ok(x.format(&options, int)?.value.to_string())
+        };
+        assert_eq!(percent(frac(1, 2)?, 10)?, "50%");
+        assert_eq!(percent(frac(1, 8)?, 10)?, "12.5%");
+        // like `SignificantFigures`, extra digits are rounded
+        assert_eq!(percent(frac(2, 3)?, 3)?, "66.7%");
+        assert_eq!(percent(-frac(1, 40)?, 10)?, "-2.5%");
+        assert_eq!(percent(3.into(), 10)?, "300%");
+        assert_eq!(percent(0.into(), 10)?, "0%");
+        Ok(())
+    }
+
+    #[test]
+    fn test_default() {
+        let zero = BigRat::default();
+        assert_eq!(zero.sign, Sign::Positive);
+        assert_eq!(zero.num, 0.into());
+        assert_eq!(zero.den, 1.into());
+        assert_eq!(zero, -BigRat::from(0));
+
+        let mut x = BigRat::from(5);
+        assert_eq!(std::mem::take(&mut x), 5.into());
+        assert_eq!(x.sign, Sign::Positive);
+        assert_eq!(x, 0.into());
+    }
+
+    #[test]
+    fn test_sum_and_product() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        let values = [2, 3, 6]
+            .into_iter()
+            .map(|d| BigRat::from(1).div(&BigRat::from(d), int))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(values.iter().sum::<BigRat>(), 1.into());
+        assert_eq!(values.clone().into_iter().sum::<BigRat>(), 1.into());
+        assert_eq!(
+            values.iter().product::<BigRat>(),
+            BigRat::from(1).div(&BigRat::from(36), int)?
+        );
+        assert_eq!(
+            [-BigRat::from(2), 3.into(), 4.into()]
+                .into_iter()
+                .product::<BigRat>(),
+            -BigRat::from(24)
+        );
+        assert_eq!(
+            [-BigRat::from(2), 2.into()].iter().sum::<BigRat>().sign,
+            Sign::Positive
+        );
+
+        let empty: [BigRat; 0] = [];
+        assert_eq!(empty.iter().sum::<BigRat>(), 0.into());
+        assert_eq!(empty.iter().product::<BigRat>(), 1.into());
+        assert_eq!(empty.into_iter().product::<BigRat>(), 1.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_exact_division_is_integer() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        // no explicit simplification needed
+        let x = BigRat::from(10).div(&BigRat::from(5), int)?;
+        assert_eq!((x.num.clone(), x.den.clone()), (2.into(), 1.into()));
+        assert_eq!(x.try_as_usize(int)?, 2);
+        let x = (-BigRat::from(21)).div(&BigRat::from(7), int)?;
+        assert_eq!((x.sign, x.num, x.den), (Sign::Negative, 3.into(), 1.into()));
+        // (3/4) / (3/8) = 24/12 = 2
+        let three_quarters = BigRat::from(3).div(&BigRat::from(4), int)?;
+        let three_eighths = BigRat::from(3).div(&BigRat::from(8), int)?;
+        let x = three_quarters.div(&three_eighths, int)?;
+        assert_eq!((x.num, x.den), (2.into(), 1.into()));
+        // non-integer results are still left unreduced
+        let x = BigRat::from(10).div(&BigRat::from(4), int)?;
+        assert_eq!((x.num, x.den), (10.into(), 4.into()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_exact_logs() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        let prec = Precision::default();
+        let eighth = BigRat::from(1).div(&BigRat::from(8), int)?;
+        let res = eighth.log2(prec, int)?;
+        assert_eq!(res.value, -BigRat::from(3));
+        assert!(res.exact);
+        let res = BigRat::from(1000).log10(prec, int)?;
+        assert_eq!(res.value, BigRat::from(3));
+        assert!(res.exact);
+        let res = BigRat::from(1).ln(prec, int)?;
+        assert_eq!(res.value, BigRat::from(0));
+        assert!(res.exact);
+        assert!(!BigRat::from(1001).log10(prec, int)?.exact);
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_pow() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        let prec = Precision::default();
+        // 3^40 needs 64 bits
+        let res = BigRat::from(3).try_pow(40.into(), 64, prec, int)?;
+        assert_eq!(res.value, BigRat::from(12_157_665_459_056_928_801));
+        assert!(matches!(
+            BigRat::from(3).try_pow(80.into(), 64, prec, int),
+            Err(FendError::ValueTooLarge)
+        ));
+        // the denominator is checked too
+        let third = BigRat::from(1).div(&3.into(), int)?;
+        assert!(matches!(
+            third.try_pow(-BigRat::from(80), 64, prec, int),
+            Err(FendError::ValueTooLarge)
+        ));
+        let huge = BigRat::from(123_456_789).pow(1_000_000_000_000.into(), prec, int);
+        assert!(matches!(huge, Err(FendError::ValueTooLarge)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_integer_div_fast_path() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        // the same integers, but with a denominator that isn't 1, so that
+        // they go through the general path
+        let unreduced = |n: u64| BigRat {
+            sign: Sign::Positive,
+            num: BigUint::from(n).mul(&3.into(), int).unwrap(),
+            den: 3.into(),
+            reduced: false,
+        };
+        for (a, b) in [(7, 2), (0, 5), (12, 4), (u64::MAX, 3)] {
+            let fast = BigRat::from(a).div(&b.into(), int)?;
+            let expected_den = if a % b == 0 { 1 } else { b };
+            assert_eq!(fast.den, expected_den.into());
+            assert_eq!(fast, unreduced(a).div(&unreduced(b), int)?);
+            assert_eq!(
+                (-BigRat::from(a)).div(&b.into(), int)?,
+                (-unreduced(a)).div(&unreduced(b), int)?
+            );
+            let (q, r) = BigRat::from(a).div_rem(b, int)?;
+            assert_eq!((q.clone(), r.clone()), unreduced(a).div_rem(b, int)?);
+            assert_eq!(q.mul(&b.into(), int)?.add(r, int)?, a.into());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_tally() -> Result<(), FendError> {
+        use super::FormatOptions;
+        use crate::format::Format;
+        use crate::num::FormattingStyle;
+
+        let int = &crate::interrupt::Never::default();
+        let tally = |x: BigRat| -> Result<String, FendError> {
+            let options = FormatOptions {
+                style: FormattingStyle::Tally,
+                ..Default::default()
+            };
+            Ok(x.format(&options, int)?.value.to_string())
+        };
+        assert_eq!(tally(7.into())?, "||||/ ||");
+        assert_eq!(tally(10.into())?, "||||/ ||||/");
+        assert_eq!(tally(3.into())?, "|||");
+        assert_eq!(tally(0.into())?, "0");
+        assert_eq!(tally(1000.into())?.matches('/').count(), 200);
+        assert!(tally(1001.into()).is_err());
+        assert!(tally(-BigRat::from(3)).is_err());
+        assert!(tally(BigRat::from(1).div(&2.into(), int)?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_factorial_base() -> Result<(), FendError> {
+        use super::FormatOptions;
+        use crate::format::Format;
+        use crate::num::FormattingStyle;
+
+        let int = &crate::interrupt::Never::default();
+        let format = |x: BigRat| -> Result<String, FendError> {
+            let options = FormatOptions {
+                style: FormattingStyle::FactorialBase,
+                ..Default::default()
+            };
+            Ok(x.format(&options, int)?.value.to_string())
+        };
+        assert_eq!(format(0.into())?, "0");
+        assert_eq!(format(1.into())?, "10");
+        assert_eq!(format(5.into())?, "210");
+        assert_eq!(format(463.into())?, "341010");
+        // 10 * 10! needs a two-digit digit
+        assert_eq!(format(36_288_000.into())?, "10:0:0:0:0:0:0:0:0:0:0");
+        assert!(format(-BigRat::from(3)).is_err());
+        assert!(format(BigRat::from(1).div(&2.into(), int)?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_scientific() -> Result<(), FendError> {
+        use super::FormatOptions;
+        use crate::format::Format;
+        use crate::num::{Base, FormattingStyle};
+
+        let int = &crate::interrupt::Never::default();
+        let format = |x: &BigRat, options: FormatOptions| -> Result<_, FendError> {
+            let options = FormatOptions {
+                style: FormattingStyle::Scientific,
+                ..options
+            };
+            let res = x.format(&options, int)?;
+            Ok((res.value.to_string(), res.exact))
+        };
+        let sci = |x: &BigRat| format(x, FormatOptions::default());
+        assert_eq!(sci(&1500.into())?, ("1.5e3".to_string(), true));
+        assert_eq!(sci(&1000.into())?, ("1e3".to_string(), true));
+        assert_eq!(sci(&0.into())?, ("0e0".to_string(), true));
+        assert_eq!(sci(&7.into())?, ("7e0".to_string(), true));
+        assert_eq!(sci(&frac(1, 1000)?)?, ("1e-3".to_string(), true));
+        assert_eq!(sci(&-frac(3, 400)?)?, ("-7.5e-3".to_string(), true));
+        assert_eq!(sci(&frac(1, 3)?)?, ("3.(3)e-1".to_string(), true));
+        let binary = FormatOptions {
+            base: Base::from_plain_base(2)?,
+            ..Default::default()
+        };
+        // the exponent is always written in decimal
+        assert_eq!(format(&12.into(), binary)?.0, "1.1e3");
+        let hex = FormatOptions {
+            base: Base::from_plain_base(16)?,
+            ..Default::default()
+        };
+        assert!(format(&12.into(), hex).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_leading_zero() -> Result<(), FendError> {
+        use super::FormatOptions;
+        use crate::format::Format;
+        use crate::num::{Base, FormattingStyle};
+
+        let int = &crate::interrupt::Never::default();
+        let format = |x: &BigRat, options: FormatOptions| -> Result<_, FendError> {
+            let options = FormatOptions {
+                leading_zero: false,
+                ..options
+            };
+            Ok(x.format(&options, int)?.value.to_string())
+        };
+        let half = BigRat::from(1).div(&2.into(), int)?;
+        let third = BigRat::from(1).div(&3.into(), int)?;
+        assert_eq!(format(&half, FormatOptions::default())?, ".5");
+        assert_eq!(format(&-half.clone(), FormatOptions::default())?, "-.5");
+        let imag = FormatOptions {
+            term: "i",
+            ..Default::default()
+        };
+        assert_eq!(format(&-half.clone(), imag)?, "-.5i");
+        let dp = FormatOptions {
+            style: FormattingStyle::DecimalPlaces(3),
+            ..Default::default()
+        };
+        assert_eq!(format(&third, dp)?, ".333");
+        // values outside (-1, 1) and integers are unaffected
+        assert_eq!(format(&BigRat::from(3).div(&2.into(), int)?, dp)?, "1.5");
+        assert_eq!(format(&0.into(), FormatOptions::default())?, "0");
+        assert_eq!(
+            format(
+                &half,
+                FormatOptions {
+                    base: Base::from_plain_base(16)?,
+                    ..Default::default()
+                }
+            )?,
+            ".8"
+        );
+        let hex = FormatOptions {
+            base: Base::HEX,
+            ..Default::default()
+        };
+        assert_eq!(format(&half, hex)?, "0x0.8");
+        // the default keeps the leading zero
+        assert_eq!(
+            half.format(&FormatOptions::default(), int)?
+                .value
+                .to_string(),
+            "0.5"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_trailing_zeros() -> Result<(), FendError> {
+        use super::{FormatOptions, TrailingZeros};
+        use crate::format::Format;
+        use crate::num::FormattingStyle;
+
+        let int = &crate::interrupt::Never::default();
+        let format = |x: &BigRat, style, trailing_zeros| -> Result<_, FendError> {
+            let options = FormatOptions {
+                style,
+                trailing_zeros,
+                term: "i",
+                ..Default::default()
+            };
+            let res = x.format(&options, int)?;
+            Ok((res.value.to_string(), res.exact))
+        };
+        let exact_float = FormattingStyle::ExactFloat;
+        let dp = FormattingStyle::DecimalPlaces(4);
+        for mode in [
+            TrailingZeros::Strip,
+            TrailingZeros::KeepOne,
+            TrailingZeros::Pad,
+        ] {
+            // recurring digits and significant figures are never changed
+            assert_eq!(
+                format(&frac(1, 3)?, FormattingStyle::Exact, mode)?,
+                ("i/3".to_string(), true)
+            );
+            assert_eq!(
+                format(&frac(201, 2)?, FormattingStyle::SignificantFigures(2), mode)?,
+                ("100i".to_string(), false)
+            );
+        }
+        assert_eq!(
+            format(&frac(5, 4)?, exact_float, TrailingZeros::Strip)?,
+            ("1.25i".to_string(), true)
+        );
+        assert_eq!(
+            format(&2.into(), exact_float, TrailingZeros::Strip)?,
+            ("2i".to_string(), true)
+        );
+        assert_eq!(
+            format(&frac(5, 4)?, exact_float, TrailingZeros::KeepOne)?,
+            ("1.25i".to_string(), true)
+        );
+        assert_eq!(
+            format(&-BigRat::from(2), exact_float, TrailingZeros::KeepOne)?,
+            ("-2.0i".to_string(), true)
+        );
+        assert_eq!(
+            format(&frac(5, 4)?, dp, TrailingZeros::Pad)?,
+            ("1.2500i".to_string(), true)
+        );
+        assert_eq!(
+            format(&frac(1, 3)?, dp, TrailingZeros::Pad)?,
+            ("0.3333i".to_string(), false)
+        );
+        assert_eq!(
+            format(&frac(1, 100_000)?, dp, TrailingZeros::Pad)?,
+            ("0.0000i".to_string(), false)
+        );
+        assert_eq!(
+            format(&2.into(), FormattingStyle::Auto, TrailingZeros::Pad)?,
+            ("2i".to_string(), true)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_series_term_cap() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        // the atan series converges far too slowly at 1 to reach 256 bits,
+        // so this only terminates because of the cap
+        let bits = 256;
+        let res = BigRat::atan_series(1.into(), false, bits, int)?;
+        let quarter_pi = BigRat::pi_internal(bits, int)?.div(&4.into(), int)?;
+        let error = res.add(-quarter_pi, int)?.abs();
+        assert!(error < BigRat::from(1).div(&500.into(), int)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_precision() -> Result<(), FendError> {
+        let int = &crate::interrupt::Never::default();
+        let ln_2 = BigRat::from(2).ln(Precision::from_digits(5), int)?.value;
+        let expected = BigRat::from(69315).div(&BigRat::from(100_000), int)?;
+        assert_eq!(ln_2, expected);
+        let sin = BigRat::from(1).sin(Precision::default(), int)?;
+        assert!(!sin.exact);
+        Ok(())
     }
 }
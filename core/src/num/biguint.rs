@@ -1,12 +1,14 @@
 use crate::error::{FendError, Interrupt};
 use crate::format::Format;
-use crate::interrupt::test_int;
+use crate::interrupt::{report_progress, test_int, test_int_every};
 use crate::num::{out_of_range, Base, Exact, Range, RangeBound};
 use crate::serialize::{
     deserialize_u64, deserialize_u8, deserialize_usize, serialize_u64, serialize_u8,
     serialize_usize,
 };
+use std::cell::RefCell;
 use std::cmp::{max, Ordering};
+use std::collections::BTreeMap;
 use std::{fmt, hash, io};
 
 #[derive(Clone)]
@@ -31,13 +33,28 @@ impl hash::Hash for BigUint {
 
 use BigUint::{Large, Small};
 
+// Computed factorials are cached so that repeated evaluations (e.g. `nCr`,
+// or `n!` followed by `(n+1)!`) can reuse earlier work. `n!` takes roughly
+// `n log2(n)` bits, so both the number of entries and the largest cached `n`
+// are capped: at the limits below the cache holds at most a few megabytes.
+const FACTORIAL_CACHE_MAX_ENTRIES: usize = 32;
+const FACTORIAL_CACHE_MAX_N: usize = 20_000;
+
+thread_local! {
+    static FACTORIAL_CACHE: RefCell<BTreeMap<usize, BigUint>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Operands with at least this many words are multiplied using the Karatsuba
+/// algorithm instead of schoolbook multiplication
+const KARATSUBA_THRESHOLD: usize = 32;
+
 #[allow(clippy::cast_possible_truncation)]
 const fn truncate(n: u128) -> u64 {
     n as u64
 }
 
 impl BigUint {
-    fn is_zero(&self) -> bool {
+    pub(crate) fn is_zero(&self) -> bool {
         match self {
             Small(n) => *n == 0,
             Large(value) => {
@@ -104,19 +121,9 @@ impl BigUint {
         })
     }
 
-    #[allow(clippy::cast_precision_loss)]
-    pub(crate) fn as_f64(&self) -> f64 {
-        match self {
-            Small(n) => *n as f64,
-            Large(v) => {
-                let mut res = 0.0;
-                for &n in v.iter().rev() {
-                    res *= u64::MAX as f64;
-                    res += n as f64;
-                }
-                res
-            }
-        }
+    /// Returns the least significant 64 bits of this number
+    pub(crate) fn low_u64(&self) -> u64 {
+        self.get(0)
     }
 
     fn make_large(&mut self) {
@@ -196,7 +203,11 @@ impl BigUint {
             return Ok(Exact::new(self, true));
         }
         let mut low_guess = Self::from(1);
-        let mut high_guess = self.clone();
+        // the root is less than 2^(floor(bits / n) + 1)
+        let high_bits = n
+            .try_as_usize(int)
+            .map_or(1, |n| self.bits() / n as u64 + 1);
+        let mut high_guess = Self::from(1).lshift_n(&high_bits.into(), int)?;
         while high_guess.clone().sub(&low_guess) > 1.into() {
             test_int(int)?;
             let mut guess = low_guess.clone().add(&high_guess);
@@ -212,6 +223,141 @@ impl BigUint {
         Ok(Exact::new(low_guess, false))
     }
 
+    /// Returns the number of significant bits, i.e. floor(log2(self)) + 1
+    /// for non-zero values
+    pub(crate) fn bits(&self) -> u64 {
+        match self {
+            Small(n) => u64::from(64 - n.leading_zeros()),
+            Large(v) => v
+                .iter()
+                .rposition(|&w| w != 0)
+                .map_or(0, |i| i as u64 * 64 + u64::from(64 - v[i].leading_zeros())),
+        }
+    }
+
+    /// Returns the digits of this number in the given base, least significant
+    /// digit first. Zero has no digits.
+    pub(crate) fn digits_in_base<I: Interrupt>(
+        &self,
+        base: u64,
+        int: &I,
+    ) -> Result<Vec<u64>, FendError> {
+        if base < 2 {
+            return Err(FendError::BaseTooSmall);
+        }
+        // extract as many digits as fit into a u128 with each division
+        let base_as_u128 = u128::from(base);
+        let mut divisor = base_as_u128;
+        let mut rounds = 1;
+        while divisor < u128::MAX / base_as_u128 {
+            divisor *= base_as_u128;
+            rounds += 1;
+        }
+        let divisor = Self::Large(vec![truncate(divisor), truncate(divisor >> 64)]);
+        let mut num = self.clone();
+        let mut digits = vec![];
+        while !num.is_zero() {
+            test_int(int)?;
+            let (quotient, remainder) = num.divmod(&divisor, int)?;
+            let mut digit_group_value =
+                u128::from(remainder.get(1)) << 64 | u128::from(remainder.get(0));
+            for _ in 0..rounds {
+                digits.push(truncate(digit_group_value % base_as_u128));
+                digit_group_value /= base_as_u128;
+            }
+            num = quotient;
+        }
+        // the most significant group is padded with zeros
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        Ok(digits)
+    }
+
+    /// Builds a number from its digits in the given base, most significant
+    /// digit first. Digits are first packed into word-sized groups, which are
+    /// then combined pairwise so that most of the work happens in a few large
+    /// (Karatsuba) multiplications instead of one multiplication per digit.
+    pub(crate) fn from_digits<I: Interrupt>(
+        digits: &[u8],
+        base: u64,
+        int: &I,
+    ) -> Result<Self, FendError> {
+        if base < 2 {
+            return Err(FendError::BaseTooSmall);
+        }
+        let mut group_size = 1;
+        let mut group_base = base;
+        while let Some(next) = group_base.checked_mul(base) {
+            group_base = next;
+            group_size += 1;
+        }
+        // groups are stored least significant first, so that only the most
+        // significant group can be shorter than `group_size`
+        let mut groups = vec![];
+        for chunk in digits.rchunks(group_size) {
+            test_int(int)?;
+            let mut value = 0;
+            for &digit in chunk {
+                value = value * base + u64::from(digit);
+            }
+            groups.push(Self::from(value));
+        }
+        let mut multiplier = Self::from(group_base);
+        while groups.len() > 1 {
+            test_int(int)?;
+            let mut combined = Vec::with_capacity(groups.len().div_ceil(2));
+            let mut iter = groups.into_iter();
+            while let Some(low) = iter.next() {
+                combined.push(match iter.next() {
+                    Some(high) => high.mul(&multiplier, int)?.add(&low),
+                    None => low,
+                });
+            }
+            groups = combined;
+            if groups.len() > 1 {
+                multiplier = multiplier.clone().mul(&multiplier, int)?;
+            }
+        }
+        Ok(groups.pop().unwrap_or_else(|| Self::from(0)))
+    }
+
+    /// Returns `floor(log_base(self))`, computed exactly by dividing out
+    /// `base^(2^i)` for decreasing `i`
+    pub(crate) fn ilog<I: Interrupt>(&self, base: &Self, int: &I) -> Result<u64, FendError> {
+        if self.is_zero() {
+            return Err(out_of_range(
+                self.fm(int)?,
+                Range {
+                    start: RangeBound::Open(0),
+                    end: RangeBound::None,
+                },
+            ));
+        }
+        if *base < 2.into() {
+            return Err(FendError::BaseTooSmall);
+        }
+        // powers[i] == base^(2^i)
+        let mut powers = vec![base.clone()];
+        while let Some(last) = powers.last() {
+            if last > self {
+                break;
+            }
+            let next = last.clone().mul(last, int)?;
+            powers.push(next);
+        }
+        let mut remaining = self.clone();
+        let mut result = 0;
+        for (i, power) in powers.iter().enumerate().rev() {
+            test_int(int)?;
+            if *power <= remaining {
+                remaining = remaining.div(power, int)?;
+                result |= 1 << i;
+            }
+        }
+        Ok(result)
+    }
+
     fn pow_internal<I: Interrupt>(&self, mut exponent: u64, int: &I) -> Result<Self, FendError> {
         let mut result = Self::from(1);
         let mut base = self.clone();
@@ -240,7 +386,7 @@ impl BigUint {
                     value.push(0);
                 }
                 for i in (0..value.len()).rev() {
-                    test_int(int)?;
+                    test_int_every(int, i)?;
                     value[i] <<= 1;
                     if i != 0 {
                         value[i] |= value[i - 1] >> 63;
@@ -256,7 +402,7 @@ impl BigUint {
             Small(n) => *n >>= 1,
             Large(value) => {
                 for i in 0..value.len() {
-                    test_int(int)?;
+                    test_int_every(int, i)?;
                     value[i] >>= 1;
                     let next = if i + 1 >= value.len() {
                         0
@@ -326,6 +472,23 @@ impl BigUint {
             *self = Self::from(0);
             return Ok(());
         }
+        if self.significant_len() >= KARATSUBA_THRESHOLD
+            && other.significant_len() >= KARATSUBA_THRESHOLD
+        {
+            *self = Self::mul_karatsuba(self, other, int)?;
+            return Ok(());
+        }
+        self.mul_schoolbook(other, int)
+    }
+
+    fn mul_schoolbook<I: Interrupt>(&mut self, other: &Self, int: &I) -> Result<(), FendError> {
+        if self.value_len() < other.value_len() {
+            // loop over the shorter number
+            let mut res = other.clone();
+            res.mul_schoolbook(self, int)?;
+            *self = res;
+            return Ok(());
+        }
         let self_clone = self.clone();
         self.make_large();
         match self {
@@ -336,16 +499,65 @@ impl BigUint {
             }
         }
         for i in 0..other.value_len() {
-            test_int(int)?;
+            test_int_every(int, i)?;
             self.add_assign_internal(&self_clone, other.get(i), i);
         }
         Ok(())
     }
 
+    /// Karatsuba multiplication: with `a = a1 * B + a0` and `b = b1 * B + b0`,
+    /// `a * b = a1 b1 B^2 + ((a0 + a1)(b0 + b1) - a0 b0 - a1 b1) B + a0 b0`,
+    /// which only needs three half-size multiplications instead of four
+    fn mul_karatsuba<I: Interrupt>(a: &Self, b: &Self, int: &I) -> Result<Self, FendError> {
+        test_int(int)?;
+        let half = max(a.significant_len(), b.significant_len()) / 2;
+        let (a0, a1) = a.split_at_word(half);
+        let (b0, b1) = b.split_at_word(half);
+        let z0 = a0.clone().mul(&b0, int)?;
+        let z2 = a1.clone().mul(&b1, int)?;
+        let z1 = a0.add(&a1).mul(&b0.add(&b1), int)?.sub(&z0).sub(&z2);
+        Ok(z2.shl_words(2 * half).add(&z1.shl_words(half)).add(&z0))
+    }
+
+    /// number of words, excluding leading zeroes
+    fn significant_len(&self) -> usize {
+        match self {
+            Small(_) => 1,
+            Large(v) => v.iter().rposition(|&w| w != 0).map_or(1, |i| i + 1),
+        }
+    }
+
+    /// splits the number into `(low, high)` such that
+    /// `self == (high << (64 * idx)) + low`
+    fn split_at_word(&self, idx: usize) -> (Self, Self) {
+        match self {
+            Large(v) if idx < v.len() && idx > 0 => {
+                (Large(v[..idx].to_vec()), Large(v[idx..].to_vec()))
+            }
+            _ => (self.clone(), Self::from(0)),
+        }
+    }
+
+    /// computes `self << (64 * words)`
+    fn shl_words(mut self, words: usize) -> Self {
+        if words == 0 || self.is_zero() {
+            return self;
+        }
+        self.make_large();
+        match &mut self {
+            Large(v) => {
+                v.splice(0..0, std::iter::repeat_n(0, words));
+            }
+            Small(_) => unreachable!(),
+        }
+        self
+    }
+
     /// computes `self += (other * mul_digit) << (64 * shift)`
     fn add_assign_internal(&mut self, other: &Self, mul_digit: u64, shift: usize) {
         let mut carry = 0;
-        for i in 0..max(self.value_len(), other.value_len() + shift) {
+        // the words below `shift` don't change
+        for i in shift..max(self.value_len(), other.value_len() + shift) {
             let a = self.get(i);
             let b = if i >= shift { other.get(i - shift) } else { 0 };
             let sum = u128::from(a) + (u128::from(b) * u128::from(mul_digit)) + u128::from(carry);
@@ -358,7 +570,60 @@ impl BigUint {
     }
 
     // Note: 0! = 1, 1! = 1
-    pub(crate) fn factorial<I: Interrupt>(mut self, int: &I) -> Result<Self, FendError> {
+    pub(crate) fn factorial<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
+        let Ok(n) = self.try_as_usize(int) else {
+            return self.factorial_uncached(int);
+        };
+        // start from the largest cached factorial that is not greater than n
+        let (start, mut res) = FACTORIAL_CACHE.with(|cache| {
+            cache
+                .borrow()
+                .range(..=n)
+                .next_back()
+                .map_or((1, Self::from(1)), |(&k, v)| (k, v.clone()))
+        });
+        if start < n {
+            let bounds = (start as u64 + 1, n as u64);
+            res = res.mul(&Self::range_product(bounds.0, bounds.1, bounds, int)?, int)?;
+        }
+        if start < n && n <= FACTORIAL_CACHE_MAX_N {
+            FACTORIAL_CACHE.with(|cache| {
+                let mut cache = cache.borrow_mut();
+                cache.insert(n, res.clone());
+                if cache.len() > FACTORIAL_CACHE_MAX_ENTRIES {
+                    // smaller factorials are the cheapest to recompute
+                    cache.pop_first();
+                }
+            });
+        }
+        Ok(res)
+    }
+
+    /// Computes the product of all integers in `low..=high`. The range is
+    /// split recursively so that the factors being multiplied have similar
+    /// sizes, which lets large products benefit from Karatsuba multiplication.
+    /// `bounds` is the full range being multiplied, for reporting progress.
+    fn range_product<I: Interrupt>(
+        low: u64,
+        high: u64,
+        bounds: (u64, u64),
+        int: &I,
+    ) -> Result<Self, FendError> {
+        test_int(int)?;
+        if high - low < 16 {
+            let mut res = Self::from(1);
+            for i in low..=high {
+                res = res.mul(&Self::from(i), int)?;
+            }
+            report_progress(int, high - bounds.0 + 1, bounds.1 - bounds.0 + 1);
+            return Ok(res);
+        }
+        let mid = low + (high - low) / 2;
+        Self::range_product(low, mid, bounds, int)?
+            .mul(&Self::range_product(mid + 1, high, bounds, int)?, int)
+    }
+
+    fn factorial_uncached<I: Interrupt>(mut self, int: &I) -> Result<Self, FendError> {
         let mut res = Self::from(1);
         while self > 1.into() {
             test_int(int)?;
@@ -562,7 +827,17 @@ impl BigUint {
     }
 
     pub(crate) fn rshift_n<I: Interrupt>(mut self, rhs: &Self, int: &I) -> Result<Self, FendError> {
-        let rhs = rhs.try_as_usize(int)?;
+        let mut rhs = rhs.try_as_usize(int)?;
+        if rhs >= 64 {
+            if let Large(v) = &mut self {
+                let words = (rhs / 64).min(v.len());
+                v.drain(..words);
+                rhs -= words * 64;
+                if v.is_empty() {
+                    return Ok(Self::from(0));
+                }
+            }
+        }
         for _ in 0..rhs {
             if self.is_zero() {
                 break;
@@ -667,60 +942,25 @@ impl Format for BigUint {
             ));
         }
 
-        let mut num = self.clone();
         Ok(
-            if num.value_len() == 1 && params.base.base_as_u8() == 10 && params.sf_limit.is_none() {
+            if self.value_len() == 1 && params.base.base_as_u8() == 10 && params.sf_limit.is_none()
+            {
                 Exact::new(
                     FormattedBigUint {
                         base: base_prefix,
-                        ty: FormattedBigUintType::Simple(num.get(0)),
+                        ty: FormattedBigUintType::Simple(self.get(0)),
                     },
                     true,
                 )
             } else {
-                let base_as_u128: u128 = params.base.base_as_u8().into();
-                let mut divisor = base_as_u128;
-                let mut rounds = 1;
-                // note that the string is reversed: this is the number of trailing zeroes while
-                // printing, but actually the number of leading zeroes in the final number
-                let mut num_trailing_zeroes = 0;
-                let mut num_leading_zeroes = 0;
-                let mut finished_counting_leading_zeroes = false;
-                while divisor
-                    < u128::MAX
-                        .checked_div(base_as_u128)
-                        .expect("base appears to be 0")
-                {
-                    divisor *= base_as_u128;
-                    rounds += 1;
-                }
-                let divisor = Self::Large(vec![truncate(divisor), truncate(divisor >> 64)]);
-                let mut output = String::with_capacity(rounds);
-                while !num.is_zero() {
-                    test_int(int)?;
-                    let divmod_res = num.divmod(&divisor, int)?;
-                    let mut digit_group_value =
-                        u128::from(divmod_res.1.get(1)) << 64 | u128::from(divmod_res.1.get(0));
-                    for _ in 0..rounds {
-                        let digit_value = digit_group_value % base_as_u128;
-                        digit_group_value /= base_as_u128;
-                        let ch = Base::digit_as_char(truncate(digit_value)).unwrap();
-                        if ch == '0' {
-                            num_trailing_zeroes += 1;
-                        } else {
-                            for _ in 0..num_trailing_zeroes {
-                                output.push('0');
-                                if !finished_counting_leading_zeroes {
-                                    num_leading_zeroes += 1;
-                                }
-                            }
-                            finished_counting_leading_zeroes = true;
-                            num_trailing_zeroes = 0;
-                            output.push(ch);
-                        }
-                    }
-                    num = divmod_res.0;
-                }
+                let digits = self.digits_in_base(params.base.base_as_u8().into(), int)?;
+                let output = digits
+                    .iter()
+                    .map(|&digit| Base::digit_as_char(digit).unwrap())
+                    .collect::<String>();
+                // note that the string is reversed: these are the trailing
+                // zeroes of the number, which don't count as significant figures
+                let num_leading_zeroes = digits.iter().take_while(|&&digit| digit == 0).count();
                 let exact = params
                     .sf_limit
                     .map_or(true, |sf| sf >= output.len() - num_leading_zeroes);
@@ -793,6 +1033,22 @@ mod tests {
     use super::BigUint;
     type Res = Result<(), crate::error::FendError>;
 
+    #[test]
+    fn test_factorial_cache() -> Res {
+        let int = &crate::interrupt::Never::default();
+        let fact = |n: u64| -> Result<BigUint, crate::error::FendError> {
+            BigUint::from(n).factorial(int)
+        };
+        assert_eq!(fact(0)?, BigUint::from(1));
+        assert_eq!(fact(1)?, BigUint::from(1));
+        assert_eq!(fact(20)?, BigUint::from(2_432_902_008_176_640_000));
+        // built incrementally from the cached 20!
+        assert_eq!(fact(22)?, fact(20)?.mul(&BigUint::from(462), int)?);
+        // smaller values must not be affected by larger cached entries
+        assert_eq!(fact(5)?, BigUint::from(120));
+        Ok(())
+    }
+
     #[test]
     fn test_sqrt() -> Res {
         let two = &BigUint::from(2);
@@ -935,6 +1191,116 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_karatsuba_multiplication() -> Res {
+        let int = &crate::interrupt::Never::default();
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random_words = |n| {
+            (0..n)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    state
+                })
+                .collect::<Vec<_>>()
+        };
+        for (a_len, b_len) in [(32, 32), (45, 70), (150, 33), (200, 200)] {
+            let a = BigUint::Large(random_words(a_len));
+            let b = BigUint::Large(random_words(b_len));
+            let mut expected = a.clone();
+            expected.mul_schoolbook(&b, int)?;
+            assert_eq!(BigUint::mul_karatsuba(&a, &b, int)?, expected);
+            assert_eq!(a.mul(&b, int)?, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_ilog() -> Res {
+        let int = &crate::interrupt::Never::default();
+        let ten = BigUint::from(10);
+        assert_eq!(BigUint::from(1).ilog(&ten, int)?, 0);
+        assert_eq!(BigUint::from(999).ilog(&ten, int)?, 2);
+        assert_eq!(BigUint::from(1000).ilog(&ten, int)?, 3);
+        assert_eq!(BigUint::from(u64::MAX).ilog(&2.into(), int)?, 63);
+        let big = BigUint::pow(&ten, &300.into(), int)?;
+        assert_eq!(big.ilog(&ten, int)?, 300);
+        assert_eq!(big.clone().sub(&1.into()).ilog(&ten, int)?, 299);
+        assert_eq!(big.ilog(&7.into(), int)?, 354);
+        assert!(BigUint::from(0).ilog(&ten, int).is_err());
+        assert!(BigUint::from(5).ilog(&1.into(), int).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bits() -> Res {
+        let int = &crate::interrupt::Never::default();
+        assert_eq!(BigUint::from(0).bits(), 0);
+        assert_eq!(BigUint::from(1).bits(), 1);
+        assert_eq!(BigUint::from(u64::MAX).bits(), 64);
+        let two_64 = BigUint::from(u64::MAX).add(&1.into());
+        assert_eq!(two_64.bits(), 65);
+        assert_eq!(two_64.clone().sub(&1.into()).bits(), 64);
+        let two_128 = two_64.clone().mul(&two_64, int)?;
+        assert_eq!(two_128.bits(), 129);
+        assert_eq!(two_128.sub(&1.into()).bits(), 128);
+        // unused high words don't count
+        assert_eq!(BigUint::Large(vec![5, 0, 0]).bits(), 3);
+        assert_eq!(BigUint::Large(vec![0, 0, 1]).bits(), 129);
+        assert_eq!(BigUint::Large(vec![0, 0]).bits(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_digits_in_base() -> Res {
+        let int = &crate::interrupt::Never::default();
+        assert_eq!(BigUint::from(0).digits_in_base(10, int)?, Vec::<u64>::new());
+        assert_eq!(
+            BigUint::from(1203).digits_in_base(10, int)?,
+            vec![3, 0, 2, 1]
+        );
+        assert_eq!(BigUint::from(10).digits_in_base(2, int)?, vec![0, 1, 0, 1]);
+        assert_eq!(BigUint::from(255).digits_in_base(16, int)?, vec![15, 15]);
+        assert_eq!(
+            BigUint::from(u64::MAX).digits_in_base(u64::MAX, int)?,
+            vec![0, 1]
+        );
+        let big = BigUint::pow(&10.into(), &100.into(), int)?;
+        let mut expected = vec![0; 100];
+        expected.push(1);
+        assert_eq!(big.digits_in_base(10, int)?, expected);
+        assert!(BigUint::from(5).digits_in_base(1, int).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_digits() -> Res {
+        let int = &crate::interrupt::Never::default();
+        assert_eq!(BigUint::from_digits(&[], 10, int)?, 0.into());
+        assert_eq!(BigUint::from_digits(&[0, 0, 7], 10, int)?, 7.into());
+        assert_eq!(BigUint::from_digits(&[1, 2, 0, 3], 10, int)?, 1203.into());
+        assert_eq!(BigUint::from_digits(&[15, 15], 16, int)?, 255.into());
+        let mut digits = vec![1];
+        digits.extend([0; 100]);
+        assert_eq!(
+            BigUint::from_digits(&digits, 10, int)?,
+            BigUint::pow(&10.into(), &100.into(), int)?
+        );
+        for (n, base) in [(12345, 10), (u64::MAX, 2), (u64::MAX, 7), (u64::MAX, 36)] {
+            let big = BigUint::pow(&n.into(), &9.into(), int)?;
+            let digits = big
+                .digits_in_base(base, int)?
+                .into_iter()
+                .rev()
+                .map(|d| u8::try_from(d).unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(BigUint::from_digits(&digits, base, int)?, big);
+        }
+        assert!(BigUint::from_digits(&[1], 1, int).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_big_multiplication() -> Res {
         let int = &crate::interrupt::Never::default();
@@ -1,7 +1,8 @@
 use crate::error::{FendError, Interrupt};
+use crate::num::bigrat;
 use crate::num::real::{self, Real};
 use crate::num::Exact;
-use crate::num::{Base, FormattingStyle};
+use crate::num::{Base, FormattingStyle, Precision, RoundingMode};
 use std::cmp::Ordering;
 use std::ops::Neg;
 use std::{fmt, io};
@@ -67,11 +68,68 @@ impl Complex {
         })
     }
 
-    pub(crate) fn pow<I: Interrupt>(self, rhs: Self, int: &I) -> Result<Exact<Self>, FendError> {
+    /// Rounds the real and imaginary parts towards zero
+    pub(crate) fn trunc<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        let real = self.real.trunc(prec, int)?;
+        let imag = self.imag.trunc(prec, int)?;
+        Ok(Exact::new(
+            Self {
+                real: real.value,
+                imag: imag.value,
+            },
+            real.exact && imag.exact,
+        ))
+    }
+
+    /// Returns the fractional parts of the real and imaginary parts
+    pub(crate) fn fract<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        let real = self.real.fract(prec, int)?;
+        let imag = self.imag.fract(prec, int)?;
+        Ok(Exact::new(
+            Self {
+                real: real.value,
+                imag: imag.value,
+            },
+            real.exact && imag.exact,
+        ))
+    }
+
+    /// Rounds the real and imaginary parts to integers
+    pub(crate) fn round<I: Interrupt>(
+        self,
+        mode: RoundingMode,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        let real = self.real.round(mode, prec, int)?;
+        let imag = self.imag.round(mode, prec, int)?;
+        Ok(Exact::new(
+            Self {
+                real: real.value,
+                imag: imag.value,
+            },
+            real.exact && imag.exact,
+        ))
+    }
+
+    pub(crate) fn pow<I: Interrupt>(
+        self,
+        rhs: Self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
         if self.imag != 0.into() || rhs.imag != 0.into() {
             return Err(FendError::ExpComplex);
         }
-        let real = self.real.pow(rhs.real, int)?;
+        let real = self.real.pow(rhs.real, prec, int)?;
         Ok(Exact::new(
             Self {
                 real: real.value,
@@ -95,7 +153,11 @@ impl Complex {
         }
     }
 
-    pub(crate) fn abs<I: Interrupt>(self, int: &I) -> Result<Exact<Self>, FendError> {
+    pub(crate) fn abs<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
         Ok(if self.imag.is_zero() {
             if self.real < 0.into() {
                 Exact::new(
@@ -127,37 +189,53 @@ impl Complex {
                 )
             }
         } else {
-            let power = self.real.pow(2.into(), int)?;
-            let power2 = self.imag.pow(2.into(), int)?;
+            let power = self.real.pow(2.into(), prec, int)?;
+            let power2 = self.imag.pow(2.into(), prec, int)?;
             let real = power.add(power2, int)?;
             let res_squared = Self {
                 real: real.value,
                 imag: 0.into(),
             };
-            let result = res_squared.root_n(&Self::from(2), int)?;
+            let result = res_squared.root_n(&Self::from(2), prec, int)?;
             result.combine(real.exact)
         })
     }
 
+    #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
     pub(crate) fn format<I: Interrupt>(
         &self,
         exact: bool,
         style: FormattingStyle,
         base: Base,
         use_parentheses: UseParentheses,
+        defaults: &bigrat::FormatOptions,
+        prec: Precision,
         int: &I,
     ) -> Result<Exact<Formatted>, FendError> {
         let style = if !exact && style == FormattingStyle::Auto {
-            FormattingStyle::DecimalPlaces(10)
+            FormattingStyle::DecimalPlaces(defaults.default_decimal_places)
         } else if self.imag != 0.into() && style == FormattingStyle::Auto {
             FormattingStyle::Exact
         } else {
             style
         };
 
+        if !exact && (!self.real.is_zero() || !self.imag.is_zero()) {
+            // only show the digits that approximate values were computed to
+            let rounded = Self {
+                real: self.real.clone().round_to_precision(prec, int)?,
+                imag: self.imag.clone().round_to_precision(prec, int)?,
+            };
+            return rounded
+                .format(true, style, base, use_parentheses, defaults, prec, int)
+                .map(|res| Exact::new(res.value, false));
+        }
+
         if self.imag.is_zero() {
             let use_parens = use_parentheses == UseParentheses::IfComplexOrFraction;
-            let x = self.real.format(base, style, false, use_parens, int)?;
+            let x = self
+                .real
+                .format(base, style, false, use_parens, defaults, prec, int)?;
             return Ok(Exact::new(
                 Formatted {
                     first_component: x.value,
@@ -171,7 +249,9 @@ impl Complex {
 
         Ok(if self.real.is_zero() {
             let use_parens = use_parentheses == UseParentheses::IfComplexOrFraction;
-            let x = self.imag.format(base, style, true, use_parens, int)?;
+            let x = self
+                .imag
+                .format(base, style, true, use_parens, defaults, prec, int)?;
             Exact::new(
                 Formatted {
                     first_component: x.value,
@@ -183,14 +263,20 @@ impl Complex {
             )
         } else {
             let mut exact = exact;
-            let real_part = self.real.format(base, style, false, false, int)?;
+            let real_part = self
+                .real
+                .format(base, style, false, false, defaults, prec, int)?;
             exact = exact && real_part.exact;
             let (positive, imag_part) = if self.imag > 0.into() {
-                (true, self.imag.format(base, style, true, false, int)?)
+                (
+                    true,
+                    self.imag
+                        .format(base, style, true, false, defaults, prec, int)?,
+                )
             } else {
                 (
                     false,
-                    (-self.imag.clone()).format(base, style, true, false, int)?,
+                    (-self.imag.clone()).format(base, style, true, false, defaults, prec, int)?,
                 )
             };
             exact = exact && imag_part.exact;
@@ -208,11 +294,16 @@ impl Complex {
         })
     }
 
-    pub(crate) fn root_n<I: Interrupt>(self, n: &Self, int: &I) -> Result<Exact<Self>, FendError> {
+    pub(crate) fn root_n<I: Interrupt>(
+        self,
+        n: &Self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
         if self.imag != 0.into() || n.imag != 0.into() {
             return Err(FendError::RootsComplex);
         }
-        let real_root = self.real.root_n(&n.real, int)?;
+        let real_root = self.real.root_n(&n.real, prec, int)?;
         Ok(Exact::new(
             Self {
                 real: real_root.value,
@@ -230,75 +321,166 @@ impl Complex {
         }
     }
 
-    pub(crate) fn sin<I: Interrupt>(self, int: &I) -> Result<Exact<Self>, FendError> {
-        Ok(self.expect_real()?.sin(int)?.apply(Self::from))
+    pub(crate) fn sin<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.sin(prec, int)?.apply(Self::from))
     }
 
-    pub(crate) fn cos<I: Interrupt>(self, int: &I) -> Result<Exact<Self>, FendError> {
-        // cos(self) == sin(pi/2 - self)
-        let pi = Exact::new(Self::pi(), true);
-        let half_pi = pi.div(Exact::new(2.into(), true), int)?;
-        let sin_arg = half_pi.add(-Exact::new(self, true), int)?;
-        Ok(sin_arg
-            .value
-            .expect_real()?
-            .sin(int)?
-            .combine(sin_arg.exact)
-            .apply(Self::from))
+    pub(crate) fn cos<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.cos(prec, int)?.apply(Self::from))
     }
 
-    pub(crate) fn tan<I: Interrupt>(self, int: &I) -> Result<Exact<Self>, FendError> {
-        let num = self.clone().sin(int)?;
-        let den = self.cos(int)?;
+    pub(crate) fn tan<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        let num = self.clone().sin(prec, int)?;
+        let den = self.cos(prec, int)?;
         num.div(den, int)
     }
 
-    pub(crate) fn asin<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.expect_real()?.asin(int)?))
+    pub(crate) fn asin<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.asin(prec, int)?.apply(Self::from))
+    }
+
+    pub(crate) fn acos<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.acos(prec, int)?.apply(Self::from))
+    }
+
+    pub(crate) fn atan<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.atan(prec, int)?.apply(Self::from))
+    }
+
+    pub(crate) fn sinh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.sinh(prec, int)?.apply(Self::from))
     }
 
-    pub(crate) fn acos<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.expect_real()?.acos(int)?))
+    pub(crate) fn cosh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.cosh(prec, int)?.apply(Self::from))
     }
 
-    pub(crate) fn atan<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.expect_real()?.atan(int)?))
+    pub(crate) fn tanh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.tanh(prec, int)?.apply(Self::from))
+    }
+
+    pub(crate) fn asinh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.asinh(prec, int)?.apply(Self::from))
+    }
+
+    pub(crate) fn acosh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.acosh(prec, int)?.apply(Self::from))
     }
 
-    pub(crate) fn sinh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.expect_real()?.sinh(int)?))
+    pub(crate) fn atanh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.atanh(prec, int)?.apply(Self::from))
     }
 
-    pub(crate) fn cosh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.expect_real()?.cosh(int)?))
+    pub(crate) fn exp<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.exp(prec, int)?.apply(Self::from))
     }
 
-    pub(crate) fn tanh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.expect_real()?.tanh(int)?))
+    pub(crate) fn ln<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.ln(prec, int)?.apply(Self::from))
     }
 
-    pub(crate) fn asinh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.expect_real()?.asinh(int)?))
+    pub(crate) fn log2<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.log2(prec, int)?.apply(Self::from))
     }
 
-    pub(crate) fn acosh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.expect_real()?.acosh(int)?))
+    pub(crate) fn log10<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.log10(prec, int)?.apply(Self::from))
     }
 
-    pub(crate) fn atanh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.expect_real()?.atanh(int)?))
+    pub(crate) fn lgamma<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.lgamma(prec, int)?.apply(Self::from))
     }
 
-    pub(crate) fn ln<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.expect_real()?.ln(int)?))
+    pub(crate) fn erf<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.erf(prec, int)?.apply(Self::from))
     }
 
-    pub(crate) fn log2<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.expect_real()?.log2(int)?))
+    pub(crate) fn erfc<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.erfc(prec, int)?.apply(Self::from))
     }
 
-    pub(crate) fn log10<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.expect_real()?.log10(int)?))
+    pub(crate) fn zeta<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self.expect_real()?.zeta(prec, int)?.apply(Self::from))
     }
 
     pub(crate) fn is_definitely_one(&self) -> bool {
@@ -330,6 +512,18 @@ impl Complex {
         ))
     }
 
+    pub(crate) fn beta<I: Interrupt>(
+        self,
+        rhs: Self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .expect_real()?
+            .beta(rhs.expect_real()?, prec, int)?
+            .apply(Self::from))
+    }
+
     pub(crate) fn permutation<I: Interrupt>(self, rhs: Self, int: &I) -> Result<Self, FendError> {
         Ok(Self::from(
             self.expect_real()?.permutation(rhs.expect_real()?, int)?,
@@ -1,6 +1,6 @@
 use crate::error::{FendError, Interrupt};
 use crate::interrupt::test_int;
-use crate::num::bigrat::BigRat;
+use crate::num::bigrat::{self, BigRat};
 use crate::num::complex::{self, Complex};
 use crate::serialize::{deserialize_usize, serialize_usize};
 use std::cmp::Ordering;
@@ -126,6 +126,8 @@ impl Dist {
                 style,
                 base,
                 use_parentheses,
+                &bigrat::FormatOptions::from_context(ctx),
+                ctx.precision,
                 int,
             )?;
             write!(out, "{}", res.value)?;
@@ -149,7 +151,15 @@ impl Dist {
             let mut first = true;
             for (num, _prob, prob_f64) in ordered_kvs {
                 let num = num
-                    .format(exact, style, base, use_parentheses, int)?
+                    .format(
+                        exact,
+                        style,
+                        base,
+                        use_parentheses,
+                        &bigrat::FormatOptions::from_context(ctx),
+                        ctx.precision,
+                        int,
+                    )?
                     .value
                     .to_string();
                 let prob_percentage = prob_f64 * 100.0;
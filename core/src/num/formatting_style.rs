@@ -19,11 +19,27 @@ pub(crate) enum FormattingStyle {
     DecimalPlaces(usize),
     /// Print with the given number of significant figures (not including any leading zeroes)
     SignificantFigures(usize),
+    /// Multiply by 100 and print with the given number of significant figures,
+    /// followed by a percent sign, e.g. 1/8 => 12.5%
+    Percent(usize),
+    /// Print a non-negative integer as that many tally marks, in groups of
+    /// five where the last mark of each group is a `/`, e.g. 7 => ||||/ ||.
+    /// Values above 1000 are an error.
+    Tally,
+    /// Print in scientific notation with an exact mantissa between 1 and the
+    /// base, e.g. 1500 => 1.5e3 or 1/3 => 3.(3)e-1
+    Scientific,
+    /// Print a non-negative integer in factorial base, where the digit `k`
+    /// places from the right is multiplied by `k!` and is at most `k`, e.g.
+    /// 463 => 341010. Digits are separated by colons if any of them is
+    /// larger than 9.
+    FactorialBase,
     /// If exact and no recurring digits: ExactFloat, if complex/imag: MixedFraction,
-    /// otherwise: DecimalPlaces(10)
+    /// otherwise: the default number of decimal places (10 unless configured)
     #[default]
     Auto,
-    /// If not exact: DecimalPlaces(10). If no recurring digits: ExactFloat.
+    /// If not exact: the default number of decimal places (10 unless
+    /// configured). If no recurring digits: ExactFloat.
     /// Other numbers: MixedFraction, albeit possibly including fractions of pi
     Exact,
 }
@@ -37,6 +53,10 @@ impl fmt::Display for FormattingStyle {
             Self::Exact => write!(f, "exact"),
             Self::DecimalPlaces(d) => write!(f, "{d} dp"),
             Self::SignificantFigures(s) => write!(f, "{s} sf"),
+            Self::Percent(s) => write!(f, "{s} sf percent"),
+            Self::Tally => write!(f, "tally"),
+            Self::Scientific => write!(f, "scientific"),
+            Self::FactorialBase => write!(f, "factorial base"),
             Self::Auto => write!(f, "auto"),
         }
     }
@@ -51,12 +71,27 @@ impl fmt::Debug for FormattingStyle {
             Self::Exact => write!(f, "exact"),
             Self::DecimalPlaces(d) => write!(f, "{d} dp"),
             Self::SignificantFigures(s) => write!(f, "{s} sf"),
+            Self::Percent(s) => write!(f, "{s} sf percent"),
+            Self::Tally => write!(f, "tally"),
+            Self::Scientific => write!(f, "scientific"),
+            Self::FactorialBase => write!(f, "factorial base"),
             Self::Auto => write!(f, "auto"),
         }
     }
 }
 
 impl FormattingStyle {
+    /// How many decimal places approximate values are shown with when no
+    /// other style is given, unless the context overrides it
+    pub(crate) const DEFAULT_DECIMAL_PLACES: usize = 10;
+
+    /// Recurring digits are only searched for within this many digits after
+    /// the point, since the period can be as long as the denominator
+    pub(crate) const DEFAULT_RECURRING_DIGIT_LIMIT: usize = 10_000;
+
+    /// How many significant figures `to percent` shows inexact values with
+    pub(crate) const DEFAULT_PERCENT_SIG_FIGS: usize = 10;
+
     pub(crate) fn serialize(&self, write: &mut impl io::Write) -> Result<(), FendError> {
         match self {
             Self::ImproperFraction => serialize_u8(1, write)?,
@@ -72,6 +107,13 @@ impl FormattingStyle {
                 serialize_usize(*s, write)?;
             }
             Self::Auto => serialize_u8(7, write)?,
+            Self::Percent(s) => {
+                serialize_u8(8, write)?;
+                serialize_usize(*s, write)?;
+            }
+            Self::Tally => serialize_u8(9, write)?,
+            Self::Scientific => serialize_u8(10, write)?,
+            Self::FactorialBase => serialize_u8(11, write)?,
         }
         Ok(())
     }
@@ -85,6 +127,10 @@ impl FormattingStyle {
             5 => Self::DecimalPlaces(deserialize_usize(read)?),
             6 => Self::SignificantFigures(deserialize_usize(read)?),
             7 => Self::Auto,
+            8 => Self::Percent(deserialize_usize(read)?),
+            9 => Self::Tally,
+            10 => Self::Scientific,
+            11 => Self::FactorialBase,
             _ => return Err(FendError::DeserializationError),
         })
     }
@@ -0,0 +1,41 @@
+/// The number of significant decimal digits that approximate results
+/// (e.g. from `sin`, `ln`, `exp` or irrational roots) are computed to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[must_use]
+pub(crate) struct Precision {
+    digits: u32,
+}
+
+impl Precision {
+    const DEFAULT_DIGITS: u32 = 20;
+    const MAX_DIGITS: u32 = 1000;
+
+    pub(crate) const fn from_digits(digits: u32) -> Self {
+        let digits = if digits == 0 {
+            1
+        } else if digits > Self::MAX_DIGITS {
+            Self::MAX_DIGITS
+        } else {
+            digits
+        };
+        Self { digits }
+    }
+
+    pub(crate) const fn digits(self) -> u32 {
+        self.digits
+    }
+
+    /// Number of bits that intermediate results are computed with. This
+    /// includes a few guard bits so that the final result can be rounded
+    /// to the requested number of decimal digits.
+    pub(crate) const fn bits(self) -> u64 {
+        // log2(10) < 3.33
+        self.digits as u64 * 333 / 100 + 16
+    }
+}
+
+impl Default for Precision {
+    fn default() -> Self {
+        Self::from_digits(Self::DEFAULT_DIGITS)
+    }
+}
@@ -2,7 +2,7 @@ use crate::error::{FendError, Interrupt};
 use crate::format::Format;
 use crate::num::bigrat::{BigRat, FormattedBigRat};
 use crate::num::Exact;
-use crate::num::{Base, FormattingStyle};
+use crate::num::{Base, FormattingStyle, Precision, RoundingMode};
 use crate::serialize::{deserialize_u8, serialize_u8};
 use std::cmp::Ordering;
 use std::ops::Neg;
@@ -44,8 +44,11 @@ impl Ord for Real {
             (Pattern::Simple(a), Pattern::Simple(b)) | (Pattern::Pi(a), Pattern::Pi(b)) => a.cmp(b),
             _ => {
                 let int = &crate::interrupt::Never::default();
-                let a = self.clone().approximate(int).unwrap();
-                let b = other.clone().approximate(int).unwrap();
+                let a = self.clone().approximate(Precision::default(), int).unwrap();
+                let b = other
+                    .clone()
+                    .approximate(Precision::default(), int)
+                    .unwrap();
                 a.cmp(&b)
             }
         }
@@ -99,18 +102,26 @@ impl Real {
         })
     }
 
-    fn approximate<I: Interrupt>(self, int: &I) -> Result<BigRat, FendError> {
+    fn approximate<I: Interrupt>(self, prec: Precision, int: &I) -> Result<BigRat, FendError> {
         match self.pattern {
             Pattern::Simple(s) => Ok(s),
-            Pattern::Pi(n) => {
-                let num = BigRat::from(3_141_592_653_589_793_238);
-                let den = BigRat::from(1_000_000_000_000_000_000);
-                let pi = num.div(&den, int)?;
-                Ok(n.mul(&pi, int)?)
-            }
+            Pattern::Pi(n) => n.mul_pi(prec, int),
         }
     }
 
+    /// Rounds to the given number of significant digits. This is used when
+    /// displaying approximate values, so that digits beyond the precision
+    /// they were computed with aren't shown.
+    pub(crate) fn round_to_precision<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Self, FendError> {
+        Ok(Self::from(
+            self.approximate(prec, int)?.round_to_precision(prec, int)?,
+        ))
+    }
+
     pub(crate) fn try_as_usize<I: Interrupt>(self, int: &I) -> Result<usize, FendError> {
         match self.pattern {
             Pattern::Simple(s) => s.try_as_usize(int),
@@ -125,16 +136,20 @@ impl Real {
     }
 
     // sin works for all real numbers
-    pub(crate) fn sin<I: Interrupt>(self, int: &I) -> Result<Exact<Self>, FendError> {
+    pub(crate) fn sin<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
         Ok(match self.pattern {
-            Pattern::Simple(s) => s.sin(int)?.apply(Self::from),
+            Pattern::Simple(s) => s.sin(prec, int)?.apply(Self::from),
             Pattern::Pi(n) => {
                 if n < 0.into() {
                     let s = Self {
                         pattern: Pattern::Pi(n),
                     };
                     // sin(-x) == -sin(x)
-                    return Ok(-Self::sin(-s, int)?);
+                    return Ok(-Self::sin(-s, prec, int)?);
                 }
                 if let Ok(integer) = n.clone().mul(&6.into(), int)?.try_as_usize(int) {
                     // values from https://en.wikipedia.org/wiki/Exact_trigonometric_values
@@ -155,70 +170,264 @@ impl Real {
                 let s = Self {
                     pattern: Pattern::Pi(n),
                 };
-                s.approximate(int)?.sin(int)?.apply(Self::from)
+                s.approximate(prec, int)?.sin(prec, int)?.apply(Self::from)
             }
         })
     }
 
-    pub(crate) fn asin<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.approximate(int)?.asin(int)?))
+    pub(crate) fn cos<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        match self.pattern {
+            Pattern::Simple(s) => Ok(s.cos(prec, int)?.apply(Self::from)),
+            Pattern::Pi(n) => {
+                // cos(x) == sin(pi/2 - x), which keeps special angles exact
+                let half = BigRat::from(1).div(&2.into(), int)?;
+                Self {
+                    pattern: Pattern::Pi(half.add(-n, int)?),
+                }
+                .sin(prec, int)
+            }
+        }
+    }
+
+    pub(crate) fn asin<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .asin(prec, int)?
+            .apply(Self::from))
     }
 
-    pub(crate) fn acos<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.approximate(int)?.acos(int)?))
+    pub(crate) fn acos<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .acos(prec, int)?
+            .apply(Self::from))
     }
 
-    pub(crate) fn atan<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.approximate(int)?.atan(int)?))
+    pub(crate) fn atan<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .atan(prec, int)?
+            .apply(Self::from))
     }
 
-    pub(crate) fn sinh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.approximate(int)?.sinh(int)?))
+    pub(crate) fn sinh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .sinh(prec, int)?
+            .apply(Self::from))
     }
 
-    pub(crate) fn cosh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.approximate(int)?.cosh(int)?))
+    pub(crate) fn cosh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .cosh(prec, int)?
+            .apply(Self::from))
     }
 
-    pub(crate) fn tanh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.approximate(int)?.tanh(int)?))
+    pub(crate) fn tanh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .tanh(prec, int)?
+            .apply(Self::from))
     }
 
-    pub(crate) fn asinh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.approximate(int)?.asinh(int)?))
+    pub(crate) fn asinh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .asinh(prec, int)?
+            .apply(Self::from))
     }
 
-    pub(crate) fn acosh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.approximate(int)?.acosh(int)?))
+    pub(crate) fn acosh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .acosh(prec, int)?
+            .apply(Self::from))
     }
 
-    pub(crate) fn atanh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.approximate(int)?.atanh(int)?))
+    pub(crate) fn atanh<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .atanh(prec, int)?
+            .apply(Self::from))
     }
 
     // For all logs: value must be greater than 0
-    pub(crate) fn ln<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.approximate(int)?.ln(int)?))
+    pub(crate) fn exp<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .exp(prec, int)?
+            .apply(Self::from))
+    }
+
+    pub(crate) fn ln<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .ln(prec, int)?
+            .apply(Self::from))
     }
 
-    pub(crate) fn log2<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.approximate(int)?.log2(int)?))
+    pub(crate) fn log2<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .log2(prec, int)?
+            .apply(Self::from))
+    }
+
+    pub(crate) fn log10<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .log10(prec, int)?
+            .apply(Self::from))
+    }
+
+    pub(crate) fn lgamma<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .lgamma(prec, int)?
+            .apply(Self::from))
+    }
+
+    pub(crate) fn erf<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .erf(prec, int)?
+            .apply(Self::from))
     }
 
-    pub(crate) fn log10<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.approximate(int)?.log10(int)?))
+    pub(crate) fn erfc<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .erfc(prec, int)?
+            .apply(Self::from))
+    }
+
+    pub(crate) fn zeta<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        Ok(self
+            .approximate(prec, int)?
+            .zeta(prec, int)?
+            .apply(Self::from))
     }
 
     pub(crate) fn factorial<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        Ok(Self::from(self.approximate(int)?.factorial(int)?))
+        Ok(Self::from(
+            self.approximate(Precision::default(), int)?
+                .factorial(int)?,
+        ))
     }
 
+    pub(crate) fn trunc<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        let exact = matches!(self.pattern, Pattern::Simple(_));
+        let res = self.approximate(prec, int)?.trunc(int)?;
+        Ok(Exact::new(Self::from(res), exact))
+    }
+
+    pub(crate) fn fract<I: Interrupt>(
+        self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        let exact = matches!(self.pattern, Pattern::Simple(_));
+        let res = self.approximate(prec, int)?.fract(int)?;
+        Ok(Exact::new(Self::from(res), exact))
+    }
+
+    pub(crate) fn round<I: Interrupt>(
+        self,
+        mode: RoundingMode,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        let exact = matches!(self.pattern, Pattern::Simple(_));
+        let res = self.approximate(prec, int)?.round(mode, int)?;
+        Ok(Exact::new(Self::from(res), exact))
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn format<I: Interrupt>(
         &self,
         base: Base,
         mut style: FormattingStyle,
         imag: bool,
         use_parens_if_fraction: bool,
+        defaults: &bigrat::FormatOptions,
+        prec: Precision,
         int: &I,
     ) -> Result<Exact<Formatted>, FendError> {
         let mut pi = false;
@@ -245,9 +454,11 @@ impl Real {
                 } else {
                     override_exact = false;
                     if style == FormattingStyle::Auto {
-                        style = FormattingStyle::DecimalPlaces(10);
+                        style = FormattingStyle::DecimalPlaces(defaults.default_decimal_places);
                     }
-                    self.clone().approximate(int)?
+                    self.clone()
+                        .approximate(prec, int)?
+                        .round_to_precision(prec, int)?
                 }
             }
         };
@@ -258,6 +469,7 @@ impl Real {
                 style,
                 term,
                 use_parens_if_fraction,
+                ..*defaults
             },
             int,
         )?;
@@ -270,7 +482,12 @@ impl Real {
         ))
     }
 
-    pub(crate) fn pow<I: Interrupt>(self, rhs: Self, int: &I) -> Result<Exact<Self>, FendError> {
+    pub(crate) fn pow<I: Interrupt>(
+        self,
+        rhs: Self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
         // x^1 == x
         if let Pattern::Simple(n) = &rhs.pattern {
             if n == &1.into() {
@@ -288,32 +505,37 @@ impl Real {
         if let (Pattern::Simple(a), Pattern::Simple(b)) =
             (self.clone().pattern, rhs.clone().pattern)
         {
-            Ok(a.pow(b, int)?.apply(Self::from))
+            Ok(a.pow(b, prec, int)?.apply(Self::from))
         } else {
             Ok(self
-                .approximate(int)?
-                .pow(rhs.approximate(int)?, int)?
+                .approximate(prec, int)?
+                .pow(rhs.approximate(prec, int)?, prec, int)?
                 .combine(false)
                 .apply(Self::from))
         }
     }
 
-    pub(crate) fn root_n<I: Interrupt>(self, n: &Self, int: &I) -> Result<Exact<Self>, FendError> {
+    pub(crate) fn root_n<I: Interrupt>(
+        self,
+        n: &Self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
         // TODO: Combining these match blocks is not currently possible because
         // 'binding by-move and by-ref in the same pattern is unstable'
         // https://github.com/rust-lang/rust/pull/76119
         Ok(match self.pattern {
             Pattern::Simple(a) => match &n.pattern {
-                Pattern::Simple(b) => a.root_n(b, int)?.apply(Self::from),
+                Pattern::Simple(b) => a.root_n(b, prec, int)?.apply(Self::from),
                 Pattern::Pi(_) => {
-                    let b = n.clone().approximate(int)?;
-                    a.root_n(&b, int)?.apply(Self::from).combine(false)
+                    let b = n.clone().approximate(prec, int)?;
+                    a.root_n(&b, prec, int)?.apply(Self::from).combine(false)
                 }
             },
             Pattern::Pi(_) => {
-                let a = self.clone().approximate(int)?;
-                let b = n.clone().approximate(int)?;
-                a.root_n(&b, int)?.apply(Self::from).combine(false)
+                let a = self.clone().approximate(prec, int)?;
+                let b = n.clone().approximate(prec, int)?;
+                a.root_n(&b, prec, int)?.apply(Self::from).combine(false)
             }
         })
     }
@@ -377,6 +599,23 @@ impl Real {
         ))
     }
 
+    pub(crate) fn beta<I: Interrupt>(
+        self,
+        rhs: Self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Exact<Self>, FendError> {
+        let args_exact = matches!(
+            (&self.pattern, &rhs.pattern),
+            (Pattern::Simple(_), Pattern::Simple(_))
+        );
+        let a = self.approximate(prec, int)?;
+        let b = rhs.approximate(prec, int)?;
+        Ok(BigRat::beta(a, b, prec, int)?
+            .combine(args_exact)
+            .apply(Self::from))
+    }
+
     pub(crate) fn permutation<I: Interrupt>(self, rhs: Self, int: &I) -> Result<Self, FendError> {
         Ok(Self::from(
             self.expect_rational()?
@@ -405,8 +644,8 @@ impl Exact<Real> {
                     args_exact,
                 ),
                 _ => {
-                    let a = self.value.approximate(int)?;
-                    let b = rhs.value.approximate(int)?;
+                    let a = self.value.approximate(Precision::default(), int)?;
+                    let b = rhs.value.approximate(Precision::default(), int)?;
                     Self::new(a.add(b, int)?.into(), false)
                 }
             },
@@ -439,7 +678,10 @@ impl Exact<Real> {
                 ),
                 Pattern::Pi(_) => Self::new(
                     Real {
-                        pattern: Pattern::Pi(a.mul(&rhs.value.clone().approximate(int)?, int)?),
+                        pattern: Pattern::Pi(a.mul(
+                            &rhs.value.clone().approximate(Precision::default(), int)?,
+                            int,
+                        )?),
                     },
                     false,
                 ),
@@ -458,7 +700,11 @@ impl Exact<Real> {
             Pattern::Simple(a) => match &rhs.value.pattern {
                 Pattern::Simple(b) => Self::new(a.div(b, int)?.into(), self.exact && rhs.exact),
                 Pattern::Pi(_) => Self::new(
-                    a.div(&rhs.value.clone().approximate(int)?, int)?.into(),
+                    a.div(
+                        &rhs.value.clone().approximate(Precision::default(), int)?,
+                        int,
+                    )?
+                    .into(),
                     false,
                 ),
             },
@@ -0,0 +1,60 @@
+use std::cmp::Ordering;
+
+/// How to round a value that can't be represented exactly
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum RoundingMode {
+    /// Truncate, i.e. round towards zero
+    TowardZero,
+    /// Round away from zero
+    AwayFromZero,
+    /// Round towards negative infinity
+    Floor,
+    /// Round towards positive infinity
+    Ceil,
+    /// Round to nearest, with ties rounding away from zero
+    HalfUp,
+    /// Round to nearest, with ties rounding to even (banker's rounding)
+    #[default]
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Returns whether this mode always rounds values with the given sign
+    /// towards zero
+    pub(crate) fn is_toward_zero(self, negative: bool) -> bool {
+        match self {
+            Self::TowardZero => true,
+            Self::Floor => !negative,
+            Self::Ceil => negative,
+            Self::AwayFromZero | Self::HalfUp | Self::HalfEven => false,
+        }
+    }
+
+    /// Decides whether a truncated magnitude needs to be incremented.
+    /// `remainder` is the discarded part of the magnitude, compared
+    /// against one half of the last kept unit.
+    pub(crate) fn rounds_up(
+        self,
+        negative: bool,
+        truncated_is_odd: bool,
+        remainder_is_zero: bool,
+        remainder_vs_half: Ordering,
+    ) -> bool {
+        if remainder_is_zero {
+            return false;
+        }
+        match self {
+            Self::TowardZero => false,
+            Self::AwayFromZero => true,
+            Self::Floor => negative,
+            Self::Ceil => !negative,
+            Self::HalfUp => remainder_vs_half != Ordering::Less,
+            Self::HalfEven => match remainder_vs_half {
+                Ordering::Less => false,
+                Ordering::Equal => truncated_is_odd,
+                Ordering::Greater => true,
+            },
+        }
+    }
+}
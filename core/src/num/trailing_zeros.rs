@@ -0,0 +1,16 @@
+/// What to do with zeros at the end of a decimal expansion. Only zeros
+/// after the decimal point are ever affected, and recurring expansions are
+/// left alone, so the printed value never changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum TrailingZeros {
+    /// Never print trailing zeros, e.g. `1.25` and `2`
+    #[default]
+    Strip,
+    /// Like `Strip`, but keep at least one digit after the decimal point
+    /// for `to float` and `to N dp`, e.g. `2.0`
+    KeepOne,
+    /// Like `KeepOne`, but pad to the requested number of decimal places
+    /// with `to N dp` and for approximate results, e.g. `1.2500`
+    Pad,
+}
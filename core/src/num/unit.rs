@@ -1,8 +1,11 @@
 use crate::ast::{BitwiseBop, Bop};
 use crate::error::{FendError, Interrupt};
+use crate::num::bigrat::BigRat;
+use crate::num::biguint::BigUint;
 use crate::num::complex::{Complex, UseParentheses};
 use crate::num::dist::Dist;
-use crate::num::{Base, FormattingStyle};
+use crate::num::real::Real;
+use crate::num::{Base, FormattingStyle, Precision, RoundingMode};
 use crate::scope::Scope;
 use crate::serialize::{deserialize_bool, deserialize_usize, serialize_bool, serialize_usize};
 use crate::{ast, ident::Ident};
@@ -292,6 +295,29 @@ impl Value {
         })
     }
 
+    pub(crate) fn beta<I: Interrupt>(
+        self,
+        rhs: Self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Self, FendError> {
+        if !self.is_unitless(int)? || !rhs.is_unitless(int)? {
+            return Err(FendError::ExpectedAUnitlessNumber);
+        }
+        let res = self
+            .value
+            .one_point()?
+            .beta(rhs.value.one_point()?, prec, int)?;
+        Ok(Self {
+            value: Dist::from(res.value),
+            unit: self.unit,
+            exact: self.exact && rhs.exact && res.exact,
+            base: self.base,
+            format: self.format,
+            simplifiable: self.simplifiable,
+        })
+    }
+
     pub(crate) fn permutation<I: Interrupt>(self, rhs: Self, int: &I) -> Result<Self, FendError> {
         if !self.is_unitless(int)? || !rhs.is_unitless(int)? {
             return Err(FendError::ExpectedAUnitlessNumber);
@@ -328,10 +354,11 @@ impl Value {
             Bop::Mul => self.mul(rhs, int),
             Bop::Div => self.div(rhs, int),
             Bop::Mod => self.modulo(rhs, int),
-            Bop::Pow => self.pow(rhs, int),
+            Bop::Pow => self.pow(rhs, context.precision, int),
             Bop::Bitwise(bitwise_bop) => self.bitwise(rhs, bitwise_bop, int),
             Bop::Combination => self.combination(rhs, int),
             Bop::Permutation => self.permutation(rhs, int),
+            Bop::Beta => self.beta(rhs, context.precision, int),
         }
     }
 
@@ -351,7 +378,12 @@ impl Value {
         Ok(self.exact && self.value.equals_int(1) && self.is_unitless(int)?)
     }
 
-    pub(crate) fn pow<I: Interrupt>(self, rhs: Self, int: &I) -> Result<Self, FendError> {
+    pub(crate) fn pow<I: Interrupt>(
+        self,
+        rhs: Self,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Self, FendError> {
         if !rhs.is_unitless(int)? {
             return Err(FendError::ExpUnitless);
         }
@@ -369,7 +401,10 @@ impl Value {
         let new_unit = Unit {
             components: new_components,
         };
-        let value = self.value.one_point()?.pow(rhs.value.one_point()?, int)?;
+        let value = self
+            .value
+            .one_point()?
+            .pow(rhs.value.one_point()?, prec, int)?;
         Ok(Self {
             value: value.value.into(),
             unit: new_unit,
@@ -402,8 +437,8 @@ impl Value {
         }
     }
 
-    pub(crate) fn abs<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        let value = self.value.one_point()?.abs(int)?;
+    pub(crate) fn abs<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        let value = self.value.one_point()?.abs(prec, int)?;
         Ok(Self {
             value: value.value.into(),
             unit: self.unit,
@@ -414,6 +449,38 @@ impl Value {
         })
     }
 
+    pub(crate) fn trunc<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        let res = self.value.one_point()?.trunc(prec, int)?;
+        Ok(Self {
+            value: res.value.into(),
+            exact: self.exact && res.exact,
+            ..self
+        })
+    }
+
+    pub(crate) fn fract<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        let res = self.value.one_point()?.fract(prec, int)?;
+        Ok(Self {
+            value: res.value.into(),
+            exact: self.exact && res.exact,
+            ..self
+        })
+    }
+
+    pub(crate) fn round<I: Interrupt>(
+        self,
+        mode: RoundingMode,
+        prec: Precision,
+        int: &I,
+    ) -> Result<Self, FendError> {
+        let res = self.value.one_point()?.round(mode, prec, int)?;
+        Ok(Self {
+            value: res.value.into(),
+            exact: self.exact && res.exact,
+            ..self
+        })
+    }
+
     pub(crate) fn make_approximate(self) -> Self {
         Self {
             value: self.value,
@@ -436,6 +503,19 @@ impl Value {
         }
     }
 
+    /// Builds an integer from its digits, most significant digit first
+    pub(crate) fn from_digits<I: Interrupt>(
+        digits: &[u8],
+        base: Base,
+        int: &I,
+    ) -> Result<Self, FendError> {
+        let n = BigUint::from_digits(digits, base.base_as_u8().into(), int)?;
+        Ok(Self {
+            value: Complex::from(Real::from(BigRat::from(n))).into(),
+            ..Self::zero_with_base(base)
+        })
+    }
+
     pub(crate) fn is_zero(&self) -> bool {
         self.value.equals_int(0)
     }
@@ -473,26 +553,6 @@ impl Value {
         })
     }
 
-    fn apply_fn<I: Interrupt>(
-        mut self,
-        f: impl FnOnce(Complex, &I) -> Result<Complex, FendError>,
-        require_unitless: bool,
-        int: &I,
-    ) -> Result<Self, FendError> {
-        self = self.remove_unit_scaling(int)?;
-        if require_unitless && !self.is_unitless(int)? {
-            return Err(FendError::ExpectedAUnitlessNumber);
-        }
-        Ok(Self {
-            value: f(self.value.one_point()?, int)?.into(),
-            unit: self.unit,
-            exact: false,
-            base: self.base,
-            format: self.format,
-            simplifiable: self.simplifiable,
-        })
-    }
-
     pub(crate) fn sample<I: Interrupt>(
         self,
         ctx: &crate::Context,
@@ -542,15 +602,16 @@ impl Value {
         context: &mut crate::Context,
         int: &I,
     ) -> Result<Self, FendError> {
+        let prec = context.precision;
         if let Ok(rad) = self
             .clone()
             .convert_angle_to_rad(scope, attrs, context, int)
         {
             Ok(rad
-                .apply_fn_exact(Complex::sin, false, int)?
+                .apply_fn_exact(|x, int| x.sin(prec, int), false, int)?
                 .convert_to(Self::unitless(), int)?)
         } else {
-            self.apply_fn_exact(Complex::sin, false, int)
+            self.apply_fn_exact(|x, int| x.sin(prec, int), false, int)
         }
     }
 
@@ -561,14 +622,15 @@ impl Value {
         context: &mut crate::Context,
         int: &I,
     ) -> Result<Self, FendError> {
+        let prec = context.precision;
         if let Ok(rad) = self
             .clone()
             .convert_angle_to_rad(scope, attrs, context, int)
         {
-            rad.apply_fn_exact(Complex::cos, false, int)?
+            rad.apply_fn_exact(|x, int| x.cos(prec, int), false, int)?
                 .convert_to(Self::unitless(), int)
         } else {
-            self.apply_fn_exact(Complex::cos, false, int)
+            self.apply_fn_exact(|x, int| x.cos(prec, int), false, int)
         }
     }
 
@@ -579,63 +641,84 @@ impl Value {
         context: &mut crate::Context,
         int: &I,
     ) -> Result<Self, FendError> {
+        let prec = context.precision;
         if let Ok(rad) = self
             .clone()
             .convert_angle_to_rad(scope, attrs, context, int)
         {
-            rad.apply_fn_exact(Complex::tan, false, int)?
+            rad.apply_fn_exact(|x, int| x.tan(prec, int), false, int)?
                 .convert_to(Self::unitless(), int)
         } else {
-            self.apply_fn_exact(Complex::tan, false, int)
+            self.apply_fn_exact(|x, int| x.tan(prec, int), false, int)
         }
     }
 
-    pub(crate) fn asin<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        self.apply_fn(Complex::asin, false, int)
+    pub(crate) fn asin<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.asin(prec, int), false, int)
+    }
+
+    pub(crate) fn acos<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.acos(prec, int), false, int)
+    }
+
+    pub(crate) fn atan<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.atan(prec, int), false, int)
+    }
+
+    pub(crate) fn sinh<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.sinh(prec, int), false, int)
+    }
+
+    pub(crate) fn cosh<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.cosh(prec, int), false, int)
+    }
+
+    pub(crate) fn tanh<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.tanh(prec, int), false, int)
     }
 
-    pub(crate) fn acos<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        self.apply_fn(Complex::acos, false, int)
+    pub(crate) fn asinh<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.asinh(prec, int), false, int)
     }
 
-    pub(crate) fn atan<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        self.apply_fn(Complex::atan, false, int)
+    pub(crate) fn acosh<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.acosh(prec, int), false, int)
     }
 
-    pub(crate) fn sinh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        self.apply_fn(Complex::sinh, false, int)
+    pub(crate) fn atanh<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.atanh(prec, int), false, int)
     }
 
-    pub(crate) fn cosh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        self.apply_fn(Complex::cosh, false, int)
+    pub(crate) fn exp<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.exp(prec, int), true, int)
     }
 
-    pub(crate) fn tanh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        self.apply_fn(Complex::tanh, false, int)
+    pub(crate) fn ln<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.ln(prec, int), true, int)
     }
 
-    pub(crate) fn asinh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        self.apply_fn(Complex::asinh, false, int)
+    pub(crate) fn log2<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.log2(prec, int), true, int)
     }
 
-    pub(crate) fn acosh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        self.apply_fn(Complex::acosh, false, int)
+    pub(crate) fn log10<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.log10(prec, int), true, int)
     }
 
-    pub(crate) fn atanh<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        self.apply_fn(Complex::atanh, false, int)
+    pub(crate) fn lgamma<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.lgamma(prec, int), true, int)
     }
 
-    pub(crate) fn ln<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        self.apply_fn(Complex::ln, true, int)
+    pub(crate) fn erf<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.erf(prec, int), true, int)
     }
 
-    pub(crate) fn log2<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        self.apply_fn(Complex::log2, true, int)
+    pub(crate) fn erfc<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.erfc(prec, int), true, int)
     }
 
-    pub(crate) fn log10<I: Interrupt>(self, int: &I) -> Result<Self, FendError> {
-        self.apply_fn(Complex::log10, true, int)
+    pub(crate) fn zeta<I: Interrupt>(self, prec: Precision, int: &I) -> Result<Self, FendError> {
+        self.apply_fn_exact(|x, int| x.zeta(prec, int), true, int)
     }
 
     pub(crate) fn format<I: Interrupt>(
@@ -791,7 +874,7 @@ impl Value {
                         res_comp.exponent = sum.value;
                         res_exact = res_exact && sum.exact && scale.exact;
 
-                        let scale = scale.value.pow(comp.exponent, int)?;
+                        let scale = scale.value.pow(comp.exponent, Precision::default(), int)?;
                         let adjusted_value = Exact {
                             value: res_value.one_point()?,
                             exact: res_exact,
@@ -1014,7 +1097,7 @@ impl Unit {
                     &Exact::new(Complex::from(5), true)
                         .div(Exact::new(Complex::from(9), true), int)?
                         .value
-                        .pow(exponent.clone(), int)?,
+                        .pow(exponent.clone(), Precision::default(), int)?,
                     int,
                 )?;
             }
@@ -4,8 +4,9 @@ use crate::{
     error::FendError,
     interrupt::test_int,
     num::{
+        bigrat::FormatOptions,
         complex::{self, Complex, UseParentheses},
-        Base, Exact, FormattingStyle,
+        Base, Exact, FormattingStyle, Precision,
     },
     Interrupt,
 };
@@ -74,11 +75,11 @@ impl UnitExponent {
                 }
             }
         }
-        let pow_result = self
-            .unit
-            .scale
-            .clone()
-            .pow(overall_exp.value.clone(), int)?;
+        let pow_result =
+            self.unit
+                .scale
+                .clone()
+                .pow(overall_exp.value.clone(), Precision::default(), int)?;
         *scale = Exact::new(scale.clone(), true).mul(&pow_result, int)?.value;
         *exact = *exact && pow_result.exact;
         Ok(())
@@ -101,8 +102,15 @@ impl UnitExponent {
         let (exact, exponent) = if exp == 1.into() {
             (true, None)
         } else {
-            let formatted =
-                exp.format(true, format, base, UseParentheses::IfComplexOrFraction, int)?;
+            let formatted = exp.format(
+                true,
+                format,
+                base,
+                UseParentheses::IfComplexOrFraction,
+                &FormatOptions::default(),
+                Precision::default(),
+                int,
+            )?;
             (formatted.exact, Some(formatted.value))
         };
         Ok(Exact::new(
@@ -1,7 +1,7 @@
 use crate::ast::Bop;
 use crate::date::{Date, DayOfWeek, Month};
 use crate::error::{FendError, Interrupt};
-use crate::num::{Base, FormattingStyle, Number};
+use crate::num::{Base, FormattingStyle, Number, RoundingMode};
 use crate::scope::Scope;
 use crate::serialize::{
     deserialize_bool, deserialize_string, deserialize_u8, deserialize_usize, serialize_bool,
@@ -281,22 +281,52 @@ impl Value {
         let arg = crate::ast::evaluate(arg, scope.clone(), attrs, context, int)?;
         Ok(Self::Num(Box::new(match func {
             BuiltInFunction::Approximately => arg.expect_num()?.make_approximate(),
-            BuiltInFunction::Abs => arg.expect_num()?.abs(int)?,
+            BuiltInFunction::Abs => arg.expect_num()?.abs(context.precision, int)?,
+            BuiltInFunction::Round => {
+                arg.expect_num()?
+                    .round(RoundingMode::HalfUp, context.precision, int)?
+            }
+            BuiltInFunction::Floor => {
+                arg.expect_num()?
+                    .round(RoundingMode::Floor, context.precision, int)?
+            }
+            BuiltInFunction::Ceil => {
+                arg.expect_num()?
+                    .round(RoundingMode::Ceil, context.precision, int)?
+            }
+            BuiltInFunction::Trunc => arg.expect_num()?.trunc(context.precision, int)?,
+            BuiltInFunction::Fract => arg.expect_num()?.fract(context.precision, int)?,
             BuiltInFunction::Sin => arg.expect_num()?.sin(scope, attrs, context, int)?,
             BuiltInFunction::Cos => arg.expect_num()?.cos(scope, attrs, context, int)?,
             BuiltInFunction::Tan => arg.expect_num()?.tan(scope, attrs, context, int)?,
-            BuiltInFunction::Asin => arg.expect_num()?.asin(int)?,
-            BuiltInFunction::Acos => arg.expect_num()?.acos(int)?,
-            BuiltInFunction::Atan => arg.expect_num()?.atan(int)?,
-            BuiltInFunction::Sinh => arg.expect_num()?.sinh(int)?,
-            BuiltInFunction::Cosh => arg.expect_num()?.cosh(int)?,
-            BuiltInFunction::Tanh => arg.expect_num()?.tanh(int)?,
-            BuiltInFunction::Asinh => arg.expect_num()?.asinh(int)?,
-            BuiltInFunction::Acosh => arg.expect_num()?.acosh(int)?,
-            BuiltInFunction::Atanh => arg.expect_num()?.atanh(int)?,
-            BuiltInFunction::Ln => arg.expect_num()?.ln(int)?,
-            BuiltInFunction::Log2 => arg.expect_num()?.log2(int)?,
-            BuiltInFunction::Log10 => arg.expect_num()?.log10(int)?,
+            BuiltInFunction::Asin => arg.expect_num()?.asin(context.precision, int)?,
+            BuiltInFunction::Acos => arg.expect_num()?.acos(context.precision, int)?,
+            BuiltInFunction::Atan => arg.expect_num()?.atan(context.precision, int)?,
+            BuiltInFunction::Sinh => arg.expect_num()?.sinh(context.precision, int)?,
+            BuiltInFunction::Cosh => arg.expect_num()?.cosh(context.precision, int)?,
+            BuiltInFunction::Tanh => arg.expect_num()?.tanh(context.precision, int)?,
+            BuiltInFunction::Asinh => arg.expect_num()?.asinh(context.precision, int)?,
+            BuiltInFunction::Acosh => arg.expect_num()?.acosh(context.precision, int)?,
+            BuiltInFunction::Atanh => arg.expect_num()?.atanh(context.precision, int)?,
+            BuiltInFunction::Exp => arg.expect_num()?.exp(context.precision, int)?,
+            BuiltInFunction::Ln => arg.expect_num()?.ln(context.precision, int)?,
+            BuiltInFunction::Log2 => arg.expect_num()?.log2(context.precision, int)?,
+            BuiltInFunction::Log10 => arg.expect_num()?.log10(context.precision, int)?,
+            BuiltInFunction::Lgamma => arg.expect_num()?.lgamma(context.precision, int)?,
+            BuiltInFunction::Zeta => arg.expect_num()?.zeta(context.precision, int)?,
+            BuiltInFunction::Erf => arg.expect_num()?.erf(context.precision, int)?,
+            BuiltInFunction::Erfc => arg.expect_num()?.erfc(context.precision, int)?,
+            BuiltInFunction::Beta => {
+                // beta takes two arguments, so return a function that takes
+                // the second one
+                let param = Ident::new_str("y");
+                let body = Expr::Bop(
+                    Bop::Beta,
+                    Box::new(Expr::Literal(Self::Num(Box::new(arg.expect_num()?)))),
+                    Box::new(Expr::Ident(param.clone())),
+                );
+                return Ok(Self::Fn(param, Box::new(body), scope));
+            }
             BuiltInFunction::Base => {
                 let n: u8 = arg
                     .expect_num()?
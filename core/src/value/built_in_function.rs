@@ -14,6 +14,11 @@ use crate::value::Value;
 pub(crate) enum BuiltInFunction {
     Approximately,
     Abs,
+    Round,
+    Floor,
+    Ceil,
+    Trunc,
+    Fract,
     Sin,
     Cos,
     Tan,
@@ -26,9 +31,15 @@ pub(crate) enum BuiltInFunction {
     Asinh,
     Acosh,
     Atanh,
+    Exp,
     Ln,
     Log2,
     Log10,
+    Lgamma,
+    Zeta,
+    Erf,
+    Erfc,
+    Beta,
     Base,
     Sample,
     Not,
@@ -73,6 +84,11 @@ impl BuiltInFunction {
         match self {
             Self::Approximately => "approximately",
             Self::Abs => "abs",
+            Self::Round => "round",
+            Self::Floor => "floor",
+            Self::Ceil => "ceil",
+            Self::Trunc => "trunc",
+            Self::Fract => "fract",
             Self::Sin => "sin",
             Self::Cos => "cos",
             Self::Tan => "tan",
@@ -85,9 +101,15 @@ impl BuiltInFunction {
             Self::Asinh => "asinh",
             Self::Acosh => "acosh",
             Self::Atanh => "atanh",
+            Self::Exp => "exp",
             Self::Ln => "ln",
             Self::Log2 => "log2",
             Self::Log10 => "log10",
+            Self::Lgamma => "lgamma",
+            Self::Zeta => "zeta",
+            Self::Erf => "erf",
+            Self::Erfc => "erfc",
+            Self::Beta => "beta",
             Self::Base => "base",
             Self::Sample => "sample",
             Self::Not => "not",
@@ -99,6 +121,11 @@ impl BuiltInFunction {
         Ok(match s {
             "approximately" => Self::Approximately,
             "abs" => Self::Abs,
+            "round" => Self::Round,
+            "floor" => Self::Floor,
+            "ceil" => Self::Ceil,
+            "trunc" => Self::Trunc,
+            "fract" => Self::Fract,
             "sin" => Self::Sin,
             "cos" => Self::Cos,
             "tan" => Self::Tan,
@@ -111,9 +138,15 @@ impl BuiltInFunction {
             "asinh" => Self::Asinh,
             "acosh" => Self::Acosh,
             "atanh" => Self::Atanh,
+            "exp" => Self::Exp,
             "ln" => Self::Ln,
             "log2" => Self::Log2,
             "log10" => Self::Log10,
+            "lgamma" => Self::Lgamma,
+            "zeta" => Self::Zeta,
+            "erf" => Self::Erf,
+            "erfc" => Self::Erfc,
+            "beta" => Self::Beta,
             "base" => Self::Base,
             "sample" => Self::Sample,
             "not" => Self::Not,
@@ -1,4 +1,4 @@
-use fend_core::{evaluate, Context};
+use fend_core::{evaluate, Context, RoundingMode, TrailingZeros};
 
 #[track_caller]
 fn test_serialization_roundtrip(context: &mut Context) {
@@ -106,17 +106,17 @@ fn version() {
 
 #[test]
 fn pi() {
-    test_eval("pi", "approx. 3.1415926535");
+    test_eval("pi", "approx. 3.1415926536");
 }
 
 #[test]
 fn pi_times_two() {
-    test_eval("pi * 2", "approx. 6.2831853071");
+    test_eval("pi * 2", "approx. 6.2831853072");
 }
 
 #[test]
 fn two_pi() {
-    test_eval("2 pi", "approx. 6.2831853071");
+    test_eval("2 pi", "approx. 6.2831853072");
 }
 
 #[test]
@@ -184,6 +184,34 @@ fn hex_leading_zeroes() {
     test_eval("0x0000_00ff", "0xff");
 }
 
+#[test]
+fn hex_fractional_digits() {
+    test_eval("0x1.8", "0x1.8");
+    test_eval("0x1.8 to decimal", "1.5");
+}
+
+#[test]
+fn octal_fractional_digits() {
+    test_eval("0o7.4 to decimal", "7.5");
+}
+
+#[test]
+fn binary_fractional_digits() {
+    test_eval_simple("0b0.011 to fraction", "0b11/0b1000");
+}
+
+#[test]
+fn custom_base_fractional_digits() {
+    test_eval("16#ff.8 to decimal", "255.5");
+}
+
+#[test]
+fn invalid_digit_for_prefixed_base() {
+    expect_error("0b102", None);
+    expect_error("0o8", None);
+    expect_error("0x1.g", None);
+}
+
 #[test]
 fn explicit_base_10_leading_zeroes() {
     test_eval("10#04", "10#4");
@@ -299,6 +327,30 @@ fn to_float_14() {
     test_eval_simple("6#0.(1) to float in base 10", "0.2");
 }
 
+#[test]
+fn to_float_long_terminating_expansion() {
+    test_eval_simple(
+        "1/2^100 to float",
+        "0.0000000000000000000000000000007888609052210118054117285652827862296732064351090230047702789306640625",
+    );
+    test_eval_simple("1/2^20 to float in base 2", "0.00000000000000000001");
+}
+
+#[test]
+fn fractional_base_conversion_terminating_in_target_base() {
+    test_eval_simple("1/3 to base 3", "0.1");
+    test_eval_simple("1/3 to base 6", "0.2");
+    test_eval_simple("1/6 to base 12", "0.2");
+}
+
+#[test]
+fn fractional_base_conversion_recurring_in_target_base() {
+    test_eval_simple("1/2 to base 3 to float", "0.(1)");
+    test_eval_simple("0.1 to base 2 to float", "0.0(0011)");
+    test_eval_simple("1/7 to base 2 to float", "0.(001)");
+    test_eval_simple("1/3 to base 16 to float", "0.(5)");
+}
+
 #[test]
 fn two_times_two() {
     test_eval("2*2", "4");
@@ -319,12 +371,12 @@ fn large_multiplication() {
 
 #[test]
 fn pi_times_pi() {
-    test_eval("pi * pi", "approx. 9.869604401");
+    test_eval("pi * pi", "approx. 9.8696044011");
 }
 
 #[test]
 fn four_pi_plus_one() {
-    test_eval("4pi + 1", "approx. 13.5663706143");
+    test_eval("4pi + 1", "approx. 13.5663706144");
 }
 
 #[test]
@@ -354,7 +406,7 @@ fn implicit_lambda_5() {
 
 #[test]
 fn implicit_lambda_6() {
-    test_eval("///sqrt! 16", "approx. 0.0416666666");
+    test_eval("///sqrt! 16", "approx. 0.0416666667");
 }
 
 #[test]
@@ -400,7 +452,7 @@ fn inverse_sin() {
 
 #[test]
 fn inverse_sin_point_five() {
-    test_eval("sin^-1 0.5", "approx. 0.5235987755");
+    test_eval("sin^-1 0.5", "approx. 0.5235987756");
 }
 
 #[test]
@@ -551,7 +603,7 @@ fn large_simple_subtraction() {
 
 #[test]
 fn three_pi_minus_two_pi() {
-    test_eval("3pi - 2pi", "approx. 3.1415926535");
+    test_eval("3pi - 2pi", "approx. 3.1415926536");
 }
 
 #[test]
@@ -569,7 +621,7 @@ fn large_simple_subtraction_2() {
 
 #[test]
 fn sqrt_half() {
-    test_eval("sqrt (1/2)", "approx. 0.7071067814");
+    test_eval("sqrt (1/2)", "approx. 0.7071067812");
 }
 
 #[test]
@@ -584,7 +636,7 @@ fn sqrt_1() {
 
 #[test]
 fn sqrt_2() {
-    test_eval("sqrt 2", "approx. 1.4142135619");
+    test_eval("sqrt 2", "approx. 1.4142135624");
 }
 
 #[test]
@@ -637,6 +689,12 @@ fn sqrt_100() {
     test_eval("sqrt 100", "10");
 }
 
+#[test]
+fn sqrt_144() {
+    test_eval("sqrt(144)", "12");
+    test_eval("sqrt(144/9)", "4");
+}
+
 #[test]
 fn sqrt_10000() {
     test_eval("sqrt 10000", "100");
@@ -704,7 +762,7 @@ fn sqrt_kg_squared_2() {
 
 #[test]
 fn lightyear_to_parsecs() {
-    test_eval("1 lightyear to parsecs", "approx. 0.3066013937 parsecs");
+    test_eval("1 lightyear to parsecs", "approx. 0.3066013938 parsecs");
 }
 
 #[test]
@@ -1025,7 +1083,7 @@ fn recurring_product_with_e() {
 
 #[test]
 fn recurring_product_with_function() {
-    test_eval("2.0(ln 5)", "approx. 3.2188758248");
+    test_eval("2.0(ln 5)", "approx. 3.2188758249");
 }
 
 #[test]
@@ -1198,20 +1256,17 @@ fn powers_15() {
 
 #[test]
 fn powers_16() {
-    test_eval("4^(1/4)", "approx. 1.4142135619");
+    test_eval("4^(1/4)", "approx. 1.4142135624");
 }
 
 #[test]
 fn powers_17() {
-    test_eval("(2/3)^(4/5)", "approx. 0.7229811807");
+    test_eval("(2/3)^(4/5)", "approx. 0.7229811808");
 }
 
 #[test]
 fn powers_18() {
-    test_eval(
-        "5.2*10^15*300^(3/2)",
-        "approx. 27019992598076723515.9873962402",
-    );
+    test_eval("5.2*10^15*300^(3/2)", "approx. 27019992598074485779");
 }
 
 #[test]
@@ -1244,6 +1299,11 @@ fn exponent_too_large() {
     expect_error("2^1e1000", None);
 }
 
+#[test]
+fn power_result_too_large() {
+    expect_error("123456789^123456789012", Some("value is too large"));
+}
+
 #[test]
 fn i_cubed() {
     expect_error("i^3", None);
@@ -1662,6 +1722,27 @@ fn different_base_13() {
     expect_error("1#0", None);
 }
 
+#[test]
+fn custom_base_out_of_range() {
+    expect_error("1#0", Some("base must be at least 2"));
+    expect_error("37#1", Some("base cannot be larger than 36"));
+    expect_error("1000#1", Some("base cannot be larger than 36"));
+    test_eval("37", "37");
+    test_eval("36#z", "36#z");
+}
+
+#[test]
+fn custom_base_literals_round_trip() {
+    test_eval("2#1010", "2#1010");
+    test_eval("2#1010.1", "2#1010.1");
+    test_eval("16#1F", "16#1f");
+    test_eval("16#ff.8", "16#ff.8");
+    test_eval("2#1010 to decimal", "10");
+    test_eval_simple("10.5 to base 16", "a.8");
+    expect_error("2#102", None);
+    expect_error("8#9", None);
+}
+
 #[test]
 fn different_base_14() {
     expect_error("2_2#0", None);
@@ -1804,12 +1885,12 @@ fn three_electroncharge() {
 
 #[test]
 fn e_to_1() {
-    test_eval("e to 1", "approx. 2.7182818284");
+    test_eval("e to 1", "approx. 2.7182818285");
 }
 
 #[test]
 fn e_in_binary() {
-    test_eval("e in binary", "approx. 10.1011011111");
+    test_eval("e in binary", "approx. 10.10111");
 }
 
 #[test]
@@ -1945,9 +2026,44 @@ fn exponents_19() {
     test_eval("0 + 0b1e-110", "0.015625");
 }
 
+#[test]
+fn hex_float_exponent() {
+    test_eval("0x1.8p3", "0xc");
+}
+
+#[test]
+fn hex_float_negative_exponent() {
+    test_eval("0x1p-2 to decimal", "0.25");
+}
+
+#[test]
+fn hex_float_exponent_is_decimal() {
+    test_eval("0xap+10 to decimal", "10240");
+}
+
+#[test]
+fn hex_float_exponent_is_exact() {
+    test_eval_simple("0 + 0x1.2p-10 to fraction", "9/8192");
+}
+
+#[test]
+fn hex_float_uppercase_exponent() {
+    test_eval("0x1P4", "0x10");
+}
+
+#[test]
+fn hex_float_missing_exponent_digits() {
+    expect_error("0x1p-", None);
+}
+
+#[test]
+fn hex_float_missing_exponent_digits_after_plus() {
+    expect_error("0x1p+", None);
+}
+
 #[test]
 fn exponents_20() {
-    test_eval("e", "approx. 2.7182818284");
+    test_eval("e", "approx. 2.7182818285");
 }
 
 #[test]
@@ -1962,12 +2078,12 @@ fn exponents_22() {
 
 #[test]
 fn exponents_23() {
-    test_eval("2e/2", "approx. 2.7182818284");
+    test_eval("2e/2", "approx. 2.7182818285");
 }
 
 #[test]
 fn exponents_24() {
-    test_eval("2e / 2", "approx. 2.7182818284");
+    test_eval("2e / 2", "approx. 2.7182818285");
 }
 
 #[test]
@@ -2125,6 +2241,24 @@ fn five_percent_times_kg() {
     test_eval("5% * 8 kg", "0.4 kg");
 }
 
+#[test]
+fn fraction_to_percent() {
+    test_eval("1/8 to percent", "12.5%");
+    test_eval("-1/40 to percent", "-2.5%");
+    test_eval("0 to percent", "0%");
+    test_eval("2/3 to percent", "approx. 66.66666667%");
+}
+
+#[test]
+fn percent_to_percent() {
+    test_eval("12.5% to percent", "12.5%");
+}
+
+#[test]
+fn kg_to_percent() {
+    expect_error("5 kg to percent", None);
+}
+
 #[test]
 fn five_percent_times_100() {
     /*
@@ -2158,7 +2292,7 @@ fn units_2() {
 
 #[test]
 fn units_3() {
-    test_eval("0m/s + 1 km/hr", "approx. 0.2777777777 m / s");
+    test_eval("0m/s + 1 km/hr", "approx. 0.2777777778 m / s");
 }
 
 #[test]
@@ -2203,7 +2337,7 @@ fn units_13() {
 
 #[test]
 fn units_14() {
-    test_eval("5 pi/2", "approx. 7.8539816339");
+    test_eval("5 pi/2", "approx. 7.853981634");
 }
 
 #[test]
@@ -2213,7 +2347,7 @@ fn units_15() {
 
 #[test]
 fn units_22() {
-    test_eval("1psi as kPa as 5dp", "approx. 6.89475 kPa");
+    test_eval("1psi as kPa as 5dp", "approx. 6.89476 kPa");
 }
 
 #[test]
@@ -2306,12 +2440,12 @@ fn units_39() {
 
 #[test]
 fn units_40() {
-    test_eval("5 foot 2 inches", "approx. 5.1666666666 feet");
+    test_eval("5 foot 2 inches", "approx. 5.1666666667 feet");
 }
 
 #[test]
 fn units_41() {
-    test_eval("5 foot 1 inch 1 inch", "approx. 5.1666666666 feet");
+    test_eval("5 foot 1 inch 1 inch", "approx. 5.1666666667 feet");
 }
 
 #[test]
@@ -2351,12 +2485,12 @@ fn mixed_fraction_to_improper_fraction() {
 
 #[test]
 fn mixed_fractions_1() {
-    test_eval("5/3", "approx. 1.6666666666");
+    test_eval("5/3", "approx. 1.6666666667");
 }
 
 #[test]
 fn mixed_fractions_2() {
-    test_eval("4 + 1 2/3", "approx. 5.6666666666");
+    test_eval("4 + 1 2/3", "approx. 5.6666666667");
 }
 
 #[test]
@@ -2424,6 +2558,83 @@ fn mixed_fractions_15() {
     expect_error("pi 1 1/2", None);
 }
 
+#[test]
+fn mixed_fractions_16() {
+    test_eval_simple("-1 1/2 to mixed_fraction", "-1 1/2");
+}
+
+#[test]
+fn mixed_fractions_17() {
+    test_eval_simple("-1 1/2 to fraction", "-3/2");
+}
+
+#[test]
+fn mixed_fractions_18() {
+    expect_error("1 1/0", Some("division by zero"));
+}
+
+#[test]
+fn mixed_fractions_roundtrip() {
+    test_eval_simple("355/113 to mixed_fraction", "3 16/113");
+    test_eval_simple("3 16/113 to fraction", "355/113");
+}
+
+#[test]
+fn to_scientific() {
+    test_eval_simple("1500 to scientific", "1.5e3");
+    test_eval_simple("1000 to scientific", "1e3");
+    test_eval_simple("-0.00025 to scientific", "-2.5e-4");
+    test_eval_simple("0 to scientific", "0e0");
+}
+
+#[test]
+fn scientific_in_hex() {
+    expect_error(
+        "1500 to hex to scientific",
+        Some("scientific notation is only supported in bases up to 14"),
+    );
+}
+
+#[test]
+fn seven_to_tally() {
+    test_eval_simple("7 to tally", "||||/ ||");
+    test_eval_simple("10 to tally", "||||/ ||||/");
+    test_eval_simple("0 to tally", "0");
+}
+
+#[test]
+fn tally_too_large() {
+    expect_error(
+        "1001 to tally",
+        Some("1001 must lie in the interval [0, 1000]"),
+    );
+}
+
+#[test]
+fn tally_of_fraction() {
+    expect_error("1.5 to tally", Some("1.5 is not an integer"));
+}
+
+#[test]
+fn to_factorial_base() {
+    test_eval_simple("463 to factorial_base", "341010");
+    test_eval_simple("0 to factorial_base", "0");
+    test_eval_simple("36288000 to factorial_base", "10:0:0:0:0:0:0:0:0:0:0");
+}
+
+#[test]
+fn negative_to_factorial_base() {
+    expect_error(
+        "-3 to factorial_base",
+        Some("negative numbers are not allowed"),
+    );
+}
+
+#[test]
+fn fraction_to_factorial_base() {
+    expect_error("1.5 to factorial_base", Some("1.5 is not an integer"));
+}
+
 #[test]
 fn lone_conversion_arrow() {
     expect_error("->", None);
@@ -2486,7 +2697,195 @@ fn abs_2_i() {
 
 #[test]
 fn abs_1_plus_i() {
-    test_eval("abs (1 + i)", "approx. 1.4142135619");
+    test_eval("abs (1 + i)", "approx. 1.4142135624");
+}
+
+#[test]
+fn round_half_away_from_zero() {
+    test_eval("round 2.5", "3");
+}
+
+#[test]
+fn round_negative_half() {
+    test_eval("round (-2.5)", "-3");
+}
+
+#[test]
+fn round_fraction() {
+    test_eval("round (7/3)", "2");
+}
+
+#[test]
+fn round_with_unit() {
+    test_eval("round (2.6 kg)", "3 kg");
+}
+
+#[test]
+fn round_pi() {
+    test_eval("round (pi * 100)", "approx. 314");
+}
+
+#[test]
+fn floor_negative() {
+    test_eval("floor (-2.5)", "-3");
+}
+
+#[test]
+fn floor_with_unit() {
+    test_eval("floor (2.6 kg)", "2 kg");
+}
+
+#[test]
+fn ceil_fraction() {
+    test_eval("ceil (7/3)", "3");
+}
+
+#[test]
+fn ceil_negative() {
+    test_eval("ceil (-2.5)", "-2");
+}
+
+#[test]
+fn trunc_negative() {
+    test_eval("trunc (-2.75)", "-2");
+}
+
+#[test]
+fn fract_negative() {
+    test_eval("fract (-2.25)", "-0.25");
+}
+
+#[test]
+fn fract_stays_exact() {
+    test_eval("fract (7/3)", "approx. 0.3333333333");
+    test_eval_simple("fract (7/3) to fraction", "1/3");
+}
+
+#[test]
+fn fract_pi_is_approximate() {
+    test_eval("fract pi", "approx. 0.1415926536");
+}
+
+#[test]
+fn rounding_pi_is_approximate() {
+    test_eval("round pi", "approx. 3");
+    test_eval("floor pi", "approx. 3");
+    test_eval("ceil (-pi)", "approx. -3");
+    test_eval("round (pi/pi)", "1");
+}
+
+#[test]
+fn trunc_pi_is_approximate() {
+    test_eval("trunc (10 pi)", "approx. 31");
+}
+
+#[test]
+fn lgamma_1000() {
+    test_eval("lgamma 1000", "approx. 5905.2204232092");
+}
+
+#[test]
+fn lgamma_negative() {
+    test_eval("lgamma (-2.5)", "approx. -0.0562437165");
+}
+
+#[test]
+fn lgamma_pole() {
+    expect_error(
+        "lgamma (-2)",
+        Some("the gamma function is undefined for zero and negative integers"),
+    );
+}
+
+#[test]
+fn beta_of_integers_is_exact() {
+    test_eval_simple("beta 2 3 to fraction", "1/12");
+}
+
+#[test]
+fn beta_half_half() {
+    test_eval("beta(1/2)(1/2)", "approx. 3.1415926536");
+}
+
+#[test]
+fn beta_large_arguments() {
+    test_eval("ln (beta 100000.5 100000)", "approx. -138634.2736355633");
+}
+
+#[test]
+fn beta_underflow() {
+    test_eval("beta 1e9 1e9", "approx. 0");
+}
+
+#[test]
+fn zeta_2() {
+    test_eval("zeta 2", "approx. 1.6449340668");
+}
+
+#[test]
+fn zeta_2_matches_pi_squared_over_6() {
+    test_eval("zeta 2 - pi^2/6", "approx. 0");
+}
+
+#[test]
+fn zeta_3() {
+    test_eval("zeta 3", "approx. 1.2020569032");
+}
+
+#[test]
+fn zeta_1() {
+    expect_error("zeta 1", None);
+}
+
+#[test]
+fn zeta_large_even() {
+    test_eval("zeta 400", "approx. 1");
+    test_eval("zeta 1000", "approx. 1");
+}
+
+#[test]
+fn zeta_huge() {
+    test_eval("zeta 1e20", "approx. 1");
+}
+
+#[test]
+fn erf_0() {
+    test_eval("erf 0", "0");
+}
+
+#[test]
+fn erfc_0() {
+    test_eval("erfc 0", "1");
+}
+
+#[test]
+fn erf_half() {
+    test_eval("erf 0.5", "approx. 0.5204998778");
+}
+
+#[test]
+fn erfc_3() {
+    test_eval("erfc 3", "approx. 0.0000220905");
+}
+
+#[test]
+fn erf_large() {
+    test_eval("erf 1000", "approx. 1");
+    test_eval("erf (-1000)", "approx. -1");
+}
+
+#[test]
+fn erfc_large() {
+    test_eval("erfc 1000", "approx. 0");
+    test_eval("erfc (-1000)", "approx. 2");
+}
+
+#[test]
+fn erf_tiny() {
+    test_eval_simple(
+        "erf 1e-30 to 5 sf",
+        "approx. 0.0000000000000000000000000000011284",
+    );
 }
 
 #[test]
@@ -2566,12 +2965,12 @@ fn positive_mixed_fraction_sum() {
 
 #[test]
 fn negative_mixed_fraction_sum() {
-    test_eval("1 2/3 + -4 5/6", "approx. -3.1666666666");
+    test_eval("1 2/3 + -4 5/6", "approx. -3.1666666667");
 }
 
 #[test]
 fn positive_mixed_fraction_subtraction() {
-    test_eval("1 2/3 - 4 5/6", "approx. -3.1666666666");
+    test_eval("1 2/3 - 4 5/6", "approx. -3.1666666667");
 }
 
 #[test]
@@ -2669,6 +3068,28 @@ fn factorial_of_three_kg() {
     expect_error("(3 kg)!", None);
 }
 
+#[test]
+fn factorial_too_large() {
+    expect_error("(10^10)!", Some("value is too large"));
+}
+
+#[test]
+fn long_literals_are_exact() {
+    test_eval(
+        "123456789012345678901234567890123456789012345678901234567890 + 1",
+        "123456789012345678901234567890123456789012345678901234567891",
+    );
+    test_eval(
+        "0x1234567890abcdef1234567890abcdef",
+        "0x1234567890abcdef1234567890abcdef",
+    );
+    test_eval(
+        "1.000000000000000000000000000000000000001 - 1",
+        "0.000000000000000000000000000000000000001",
+    );
+    test_eval_simple("0.12(345) to fraction", "4111/33300");
+}
+
 #[test]
 fn recurring_digits_1() {
     test_eval_simple("9/11 to float", "0.(81)");
@@ -2812,7 +3233,7 @@ fn sin_1_percent() {
 
 #[test]
 fn atan_1_percent() {
-    test_eval("atan (1%)", "approx. 0.0099996666");
+    test_eval("atan (1%)", "approx. 0.0099996667");
 }
 
 #[test]
@@ -2942,7 +3363,7 @@ fn cos_0() {
 
 #[test]
 fn cos_1() {
-    test_eval("cos 1", "approx. 0.5403023058");
+    test_eval("cos 1", "approx. 0.5403023059");
 }
 
 #[test]
@@ -3052,7 +3473,7 @@ fn cos_minus_5_pi_over_3() {
 
 #[test]
 fn tau() {
-    test_eval("tau", "approx. 6.2831853071");
+    test_eval("tau", "approx. 6.2831853072");
 }
 
 #[test]
@@ -3062,12 +3483,12 @@ fn sin_tau_over_two() {
 
 #[test]
 fn greek_pi_symbol() {
-    test_eval("π", "approx. 3.1415926535");
+    test_eval("π", "approx. 3.1415926536");
 }
 
 #[test]
 fn greek_tau_symbol() {
-    test_eval("τ", "approx. 6.2831853071");
+    test_eval("τ", "approx. 6.2831853072");
 }
 
 #[test]
@@ -3087,7 +3508,7 @@ fn tan_2pi() {
 
 #[test]
 fn asin_1() {
-    test_eval("asin 1", "approx. 1.5707963267");
+    test_eval("asin 1", "approx. 1.5707963268");
 }
 
 #[test]
@@ -3112,7 +3533,7 @@ fn asin_minus_one_point_zero_one() {
 
 #[test]
 fn acos_0() {
-    test_eval("acos 0", "approx. 1.5707963267");
+    test_eval("acos 0", "approx. 1.5707963268");
 }
 
 #[test]
@@ -3137,27 +3558,27 @@ fn acos_minus_one_point_zero_one() {
 
 #[test]
 fn atan_1() {
-    test_eval("atan 1", "approx. 0.7853981633");
+    test_eval("atan 1", "approx. 0.7853981634");
 }
 
 #[test]
 fn sinh_0() {
-    test_eval("sinh 0", "approx. 0");
+    test_eval("sinh 0", "0");
 }
 
 #[test]
 fn cosh_0() {
-    test_eval("cosh 0", "approx. 1");
+    test_eval("cosh 0", "1");
 }
 
 #[test]
 fn tanh_0() {
-    test_eval("tanh 0", "approx. 0");
+    test_eval("tanh 0", "0");
 }
 
 #[test]
 fn asinh_0() {
-    test_eval("asinh 0", "approx. 0");
+    test_eval("asinh 0", "0");
 }
 
 #[test]
@@ -3165,6 +3586,11 @@ fn acosh_0() {
     expect_error("acosh 0", None);
 }
 
+#[test]
+fn acosh_1() {
+    test_eval("acosh 1", "0");
+}
+
 #[test]
 fn acosh_2() {
     test_eval("acosh 2", "approx. 1.3169578969");
@@ -3172,7 +3598,7 @@ fn acosh_2() {
 
 #[test]
 fn atanh_0() {
-    test_eval("atanh 0", "approx. 0");
+    test_eval("atanh 0", "0");
 }
 
 #[test]
@@ -3205,9 +3631,29 @@ fn atanh_minus_1() {
     expect_error("atanh (-1)", None);
 }
 
+#[test]
+fn inverse_hyperbolic_functions_are_precise() {
+    test_eval("asinh 1", "approx. 0.881373587");
+    test_eval("asinh (-1)", "approx. -0.881373587");
+    test_eval("asinh 1e100", "approx. 230.95165648");
+    test_eval("atanh 0.5", "approx. 0.5493061443");
+    test_eval("atanh (-0.9999999999)", "approx. -11.8594990552");
+    test_eval("acosh 1.0000000001", "approx. 0.0000141421");
+    // these are beyond the precision of an f64
+    test_eval_simple("asinh 1e-20 to 5 sf", "approx. 0.00000000000000000001");
+    test_eval_simple("acosh (1 + 1e-30) to 5 sf", "approx. 0.0000000000000014142");
+    expect_error("acosh 0.5", Some("0.5 must lie in the interval [1, ∞)"));
+    expect_error("atanh 1", Some("1 must lie in the interval (-1, 1)"));
+}
+
 #[test]
 fn ln_2() {
-    test_eval("ln 2", "approx. 0.6931471805");
+    test_eval("ln 2", "approx. 0.6931471806");
+}
+
+#[test]
+fn ln_1() {
+    test_eval("ln 1", "0");
 }
 
 #[test]
@@ -3220,49 +3666,67 @@ fn exp_2() {
     test_eval("exp 2", "approx. 7.3890560989");
 }
 
+#[test]
+fn exp_100_to_precision() {
+    test_eval(
+        "exp 100 to 20 sf",
+        "approx. 26881171418161354484000000000000000000000000",
+    );
+}
+
+#[test]
+fn e_to_many_dp() {
+    test_eval_simple("e to 19 dp", "approx. 2.7182818284590452354");
+}
+
+#[test]
+fn cos_1_to_many_dp() {
+    test_eval_simple("cos 1 to 20 dp", "approx. 0.5403023058681397174");
+}
+
 #[test]
 fn log10_100() {
-    test_eval("log10 100", "approx. 2");
+    test_eval("log10 100", "2");
 }
 
 #[test]
 fn log10_1000() {
-    test_eval("log10 1000", "approx. 3");
+    test_eval("log10 1000", "3");
 }
 
 #[test]
 fn log10_10000() {
-    test_eval("log10 10000", "approx. 4");
+    test_eval("log10 10000", "4");
 }
 
 #[test]
 fn log10_100000() {
-    test_eval("log10 100000", "approx. 5");
+    test_eval("log10 100000", "5");
 }
 
 #[test]
 fn log_100() {
-    test_eval("log 100", "approx. 2");
+    test_eval("log 100", "2");
 }
 
 #[test]
 fn log_1000() {
-    test_eval("log 1000", "approx. 3");
+    test_eval("log 1000", "3");
 }
 
 #[test]
 fn log_10000() {
-    test_eval("log 10000", "approx. 4");
+    test_eval("log 10000", "4");
 }
 
 #[test]
 fn log_100000() {
-    test_eval("log 100000", "approx. 5");
+    test_eval("log 100000", "5");
 }
 
 #[test]
 fn log2_65536() {
-    test_eval("log2 65536", "approx. 16");
+    test_eval("log2 65536", "16");
 }
 
 #[test]
@@ -3355,6 +3819,25 @@ fn sqrt_minus_two_i() {
     expect_error("sqrt (-2i)", None);
 }
 
+#[test]
+fn odd_roots_of_negative_numbers() {
+    test_eval("cbrt (-27)", "-3");
+    test_eval("(-27)^(1/3)", "-3");
+    test_eval("(-8)^(2/3)", "4");
+    test_eval("(-8)^(-1/3)", "-0.5");
+    test_eval("(-32)^(1/5)", "-2");
+    test_eval("(-2)^(1/3)", "approx. -1.2599210499");
+    test_eval("cbrt (-1kg^3)", "-1 kg");
+    expect_error(
+        "(-4)^(1/2)",
+        Some("roots of negative numbers are not supported"),
+    );
+    expect_error(
+        "(-16)^(3/4)",
+        Some("roots of negative numbers are not supported"),
+    );
+}
+
 #[test]
 fn cbrt_i() {
     expect_error("cbrt i", None);
@@ -3404,7 +3887,7 @@ fn lambda_2() {
 fn lambda_3() {
     test_eval(
         "(cis: (cis (pi/3))) (x: cos x + i * (sin x))",
-        "approx. 0.5 + 0.8660254037i",
+        "approx. 0.5 + 0.8660254038i",
     );
 }
 
@@ -3545,7 +4028,7 @@ fn cis_minus_two_pi() {
 
 #[test]
 fn cis_pi_over_six() {
-    test_eval("cis (pi/6)", "approx. 0.8660254037 + 0.5i");
+    test_eval("cis (pi/6)", "approx. 0.8660254038 + 0.5i");
 }
 
 #[test]
@@ -4149,32 +4632,32 @@ fn sf_3() {
 
 #[test]
 fn sf_4() {
-    test_eval("1234567.55645 to 4 sf", "approx. 1234000");
+    test_eval("1234567.55645 to 4 sf", "approx. 1235000");
 }
 
 #[test]
 fn sf_5() {
-    test_eval("1234567.55645 to 5 sf", "approx. 1234500");
+    test_eval("1234567.55645 to 5 sf", "approx. 1234600");
 }
 
 #[test]
 fn sf_6() {
-    test_eval("1234567.55645 to 6 sf", "approx. 1234560");
+    test_eval("1234567.55645 to 6 sf", "approx. 1234570");
 }
 
 #[test]
 fn sf_7() {
-    test_eval("1234567.55645 to 7 sf", "approx. 1234567");
+    test_eval("1234567.55645 to 7 sf", "approx. 1234568");
 }
 
 #[test]
 fn sf_8() {
-    test_eval("1234567.55645 to 8 sf", "approx. 1234567.5");
+    test_eval("1234567.55645 to 8 sf", "approx. 1234567.6");
 }
 
 #[test]
 fn sf_9() {
-    test_eval("1234567.55645 to 9 sf", "approx. 1234567.55");
+    test_eval("1234567.55645 to 9 sf", "approx. 1234567.56");
 }
 
 #[test]
@@ -4214,12 +4697,21 @@ fn sf_small_3() {
 
 #[test]
 fn sf_small_4() {
-    test_eval_simple("pi / 1000000 to 4 sf", "approx. 0.000003141");
+    test_eval_simple("pi / 1000000 to 4 sf", "approx. 0.000003142");
+}
+
+#[test]
+fn small_value_dp_vs_sf() {
+    // dp counts digits after the point, sf counts from the first nonzero digit
+    test_eval_simple("0.000123456 to 3 dp", "approx. 0");
+    test_eval_simple("0.000123456 to 3 sf", "approx. 0.000123");
+    test_eval_simple("-0.000123456 to 3 sf", "approx. -0.000123");
+    test_eval_simple("0.000123456 to 7 dp", "approx. 0.0001235");
 }
 
 #[test]
 fn sf_small_5() {
-    test_eval_simple("pi / 1000000 to 5 sf", "approx. 0.0000031415");
+    test_eval_simple("pi / 1000000 to 5 sf", "approx. 0.0000031416");
 }
 
 #[test]
@@ -4229,12 +4721,12 @@ fn sf_small_6() {
 
 #[test]
 fn sf_small_7() {
-    test_eval_simple("pi / 1000000 to 7 sf", "approx. 0.000003141592");
+    test_eval_simple("pi / 1000000 to 7 sf", "approx. 0.000003141593");
 }
 
 #[test]
 fn sf_small_8() {
-    test_eval_simple("pi / 1000000 to 8 sf", "approx. 0.0000031415926");
+    test_eval_simple("pi / 1000000 to 8 sf", "approx. 0.0000031415927");
 }
 
 #[test]
@@ -4244,12 +4736,12 @@ fn sf_small_9() {
 
 #[test]
 fn sf_small_10() {
-    test_eval_simple("pi / 1000000 to 10 sf", "approx. 0.000003141592653");
+    test_eval_simple("pi / 1000000 to 10 sf", "approx. 0.000003141592654");
 }
 
 #[test]
 fn sf_small_11() {
-    test_eval_simple("pi / 1000000 to 11 sf", "approx. 0.0000031415926535");
+    test_eval_simple("pi / 1000000 to 11 sf", "approx. 0.0000031415926536");
 }
 
 #[test]
@@ -4279,12 +4771,12 @@ fn million_pi_3_sf() {
 
 #[test]
 fn million_pi_4_sf() {
-    test_eval_simple("1e6 pi to 4 sf", "approx. 3141000");
+    test_eval_simple("1e6 pi to 4 sf", "approx. 3142000");
 }
 
 #[test]
 fn million_pi_5_sf() {
-    test_eval_simple("1e6 pi to 5 sf", "approx. 3141500");
+    test_eval_simple("1e6 pi to 5 sf", "approx. 3141600");
 }
 
 #[test]
@@ -4294,12 +4786,12 @@ fn million_pi_6_sf() {
 
 #[test]
 fn million_pi_7_sf() {
-    test_eval_simple("1e6 pi to 7 sf", "approx. 3141592");
+    test_eval_simple("1e6 pi to 7 sf", "approx. 3141593");
 }
 
 #[test]
 fn million_pi_8_sf() {
-    test_eval_simple("1e6 pi to 8 sf", "approx. 3141592.6");
+    test_eval_simple("1e6 pi to 8 sf", "approx. 3141592.7");
 }
 
 #[test]
@@ -4309,7 +4801,7 @@ fn million_pi_9_sf() {
 
 #[test]
 fn million_pi_10_sf() {
-    test_eval_simple("1e6 pi to 10 sf", "approx. 3141592.653");
+    test_eval_simple("1e6 pi to 10 sf", "approx. 3141592.654");
 }
 
 #[test]
@@ -4329,17 +4821,17 @@ fn large_integer_to_3_sf() {
 
 #[test]
 fn large_integer_to_4_sf() {
-    test_eval_simple("1234567 to 4 sf", "approx. 1234000");
+    test_eval_simple("1234567 to 4 sf", "approx. 1235000");
 }
 
 #[test]
 fn large_integer_to_5_sf() {
-    test_eval_simple("1234567 to 5 sf", "approx. 1234500");
+    test_eval_simple("1234567 to 5 sf", "approx. 1234600");
 }
 
 #[test]
 fn large_integer_to_6_sf() {
-    test_eval_simple("1234567 to 6 sf", "approx. 1234560");
+    test_eval_simple("1234567 to 6 sf", "approx. 1234570");
 }
 
 #[test]
@@ -4364,7 +4856,7 @@ fn large_integer_to_10_sf() {
 
 #[test]
 fn trailing_zeroes_sf_1() {
-    test_eval_simple("1234560 to 5sf", "approx. 1234500");
+    test_eval_simple("1234560 to 5sf", "approx. 1234600");
 }
 
 #[test]
@@ -4417,12 +4909,12 @@ fn square_m_to_sqft() {
 
 #[test]
 fn test_hex_unit_conversion() {
-    test_eval_simple("1 yard lb to hex to kg m to 3sf", "approx. 0.6a2 kg m");
+    test_eval_simple("1 yard lb to hex to kg m to 3sf", "approx. 0.6a3 kg m");
 }
 
 #[test]
 fn test_hex_unit_conversion_complex() {
-    test_eval_simple("i yard lb to hex to kg m to 3sf", "approx. 0.6a2 i kg m");
+    test_eval_simple("i yard lb to hex to kg m to 3sf", "approx. 0.6a3 i kg m");
 }
 
 #[ignore]
@@ -4546,7 +5038,7 @@ fn kelvin_plus_celsius() {
 
 #[test]
 fn kelvin_plus_fahrenheit() {
-    test_eval("1K+1°F", "approx. 1.5555555555 K");
+    test_eval("1K+1°F", "approx. 1.5555555556 K");
 }
 
 #[test]
@@ -4561,12 +5053,12 @@ fn fahrenheit_plus_kelvin() {
 
 #[test]
 fn celsius_plus_fahrenheit() {
-    test_eval("1°C+1°F", "approx. 1.5555555555 °C");
+    test_eval("1°C+1°F", "approx. 1.5555555556 °C");
 }
 
 #[test]
 fn celsius_plus_rankine() {
-    test_eval("1°C+1°R", "approx. 1.5555555555 °C");
+    test_eval("1°C+1°R", "approx. 1.5555555556 °C");
 }
 
 #[test]
@@ -4586,7 +5078,7 @@ fn celsius_plus_millikelvin() {
 
 #[test]
 fn joule_per_kelvin_to_joule_per_fahrenheit() {
-    test_eval("1J/K to J/°F", "approx. 0.5555555555 J / °F");
+    test_eval("1J/K to J/°F", "approx. 0.5555555556 J / °F");
 }
 
 #[test]
@@ -4731,7 +5223,7 @@ fn string_debug_representation() {
 
 #[test]
 fn cis_4() {
-    test_eval("cis 4", "approx. -0.6536436208 - 0.7568024953i");
+    test_eval("cis 4", "approx. -0.6536436209 - 0.7568024953i");
 }
 
 #[test]
@@ -4791,7 +5283,7 @@ fn triple_string_concatenation() {
 
 #[test]
 fn number_to_string() {
-    test_eval_simple("\"pi = \" + (pi to string)", "pi = approx. 3.1415926535");
+    test_eval_simple("\"pi = \" + (pi to string)", "pi = approx. 3.1415926536");
 }
 
 #[test]
@@ -5309,7 +5801,7 @@ fn day_of_week_type_name() {
 
 #[test]
 fn phi() {
-    test_eval("phi", "approx. 1.6180339886");
+    test_eval("phi", "approx. 1.6180339887");
 }
 
 #[test]
@@ -5319,7 +5811,7 @@ fn five_dollars() {
 
 #[test]
 fn dollar_prefix() {
-    test_eval_simple("$200/3 to 2dp", "approx. $66.66");
+    test_eval_simple("$200/3 to 2dp", "approx. $66.67");
 }
 
 #[test]
@@ -5492,12 +5984,12 @@ fn planck() {
 
 #[test]
 fn implicit_unit_fudging() {
-    test_eval("5'1 to m to 2dp", "approx. 1.54 m");
+    test_eval("5'1 to m to 2dp", "approx. 1.55 m");
 }
 
 #[test]
 fn implicit_unit_fudging_2() {
-    test_eval("0'1 to m to 2dp", "approx. 0.02 m");
+    test_eval("0'1 to m to 2dp", "approx. 0.03 m");
 }
 
 #[test]
@@ -5510,7 +6002,7 @@ fn implicit_unit_fudging_3() {
 
 #[test]
 fn implicit_unit_fudging_4() {
-    test_eval("5'1 + 5m", "approx. 21.4875328083'");
+    test_eval("5'1 + 5m", "approx. 21.4875328084'");
 }
 
 #[test]
@@ -5533,12 +6025,12 @@ fn millifarad() {
 
 #[test]
 fn point() {
-    test_eval("0.5 points to mm", "approx. 0.1763888888 mm");
+    test_eval("0.5 points to mm", "approx. 0.1763888889 mm");
 }
 
 #[test]
 fn rad_per_sec() {
-    test_eval("10 RPM to rad/s", "approx. 1.0471975511 rad / s")
+    test_eval("10 RPM to rad/s", "approx. 1.0471975512 rad / s")
 }
 
 #[test]
@@ -5559,7 +6051,7 @@ fn unknown_argument_error_msg() {
 
 #[test]
 fn log10_cancelled_units() {
-    test_eval("log10 (1m / (1m", "approx. 0");
+    test_eval("log10 (1m / (1m", "0");
 }
 
 #[test]
@@ -5707,3 +6199,286 @@ fn centimeter_no_currencies() {
         "1 centimeter"
     );
 }
+
+#[test]
+fn custom_precision() {
+    let mut ctx = Context::new();
+    ctx.set_precision(5);
+    assert_eq!(
+        evaluate("sqrt 2", &mut ctx).unwrap().get_main_result(),
+        "approx. 1.4142"
+    );
+    assert_eq!(
+        evaluate("ln 2", &mut ctx).unwrap().get_main_result(),
+        "approx. 0.69315"
+    );
+    ctx.set_precision(40);
+    assert_eq!(
+        evaluate("sin 1 to 35 dp", &mut ctx)
+            .unwrap()
+            .get_main_result(),
+        "approx. 0.841470984807896506652502321630299"
+    );
+    assert_eq!(
+        evaluate("atan 1 * 4 to 35 dp", &mut ctx)
+            .unwrap()
+            .get_main_result(),
+        "approx. 3.14159265358979323846264338327950288"
+    );
+}
+
+#[test]
+fn custom_precision_integer_parts() {
+    let mut ctx = Context::new();
+    ctx.set_precision(40);
+    assert_eq!(
+        evaluate("trunc (10^30 pi)", &mut ctx)
+            .unwrap()
+            .get_main_result(),
+        "approx. 3141592653589793238462643383279"
+    );
+    assert_eq!(
+        evaluate("fract (10^30 pi) to 9 dp", &mut ctx)
+            .unwrap()
+            .get_main_result(),
+        "approx. 0.502884197"
+    );
+    assert_eq!(
+        evaluate("floor (10^30 pi)", &mut ctx)
+            .unwrap()
+            .get_main_result(),
+        "approx. 3141592653589793238462643383279"
+    );
+    assert_eq!(
+        evaluate("round (10^30 pi)", &mut ctx)
+            .unwrap()
+            .get_main_result(),
+        "approx. 3141592653589793238462643383280"
+    );
+}
+
+#[derive(Default)]
+struct ProgressRecorder {
+    reports: std::cell::RefCell<Vec<f64>>,
+}
+
+impl fend_core::Interrupt for ProgressRecorder {
+    fn should_interrupt(&self) -> bool {
+        false
+    }
+
+    fn progress(&self, fraction: f64) {
+        self.reports.borrow_mut().push(fraction);
+    }
+}
+
+#[test]
+fn progress_is_reported() {
+    for input in ["2000!", "sin 1", "1/7 to 500 dp", "1/99991 to float"] {
+        let recorder = ProgressRecorder::default();
+        fend_core::evaluate_with_interrupt(input, &mut Context::new(), &recorder).unwrap();
+        let reports = recorder.reports.borrow();
+        assert!(!reports.is_empty(), "no progress reported for {input}");
+        assert!(reports.iter().all(|&f| (0.0..=1.0).contains(&f)));
+    }
+}
+
+struct CheckCounter {
+    interval: usize,
+    checks: std::cell::Cell<usize>,
+}
+
+impl fend_core::Interrupt for CheckCounter {
+    fn should_interrupt(&self) -> bool {
+        self.checks.set(self.checks.get() + 1);
+        false
+    }
+
+    fn check_interval(&self) -> usize {
+        self.interval
+    }
+}
+
+#[test]
+fn interrupt_check_interval() {
+    let count_checks = |interval| {
+        let counter = CheckCounter {
+            interval,
+            checks: std::cell::Cell::new(0),
+        };
+        let res =
+            fend_core::evaluate_with_interrupt("1/7 to 9996 dp", &mut Context::new(), &counter)
+                .unwrap();
+        assert!(res.get_main_result().ends_with("142857"));
+        counter.checks.get()
+    };
+    let every_iteration = count_checks(1);
+    assert!(every_iteration > 9996);
+    assert_eq!(count_checks(0), every_iteration);
+    assert!(count_checks(4096) < every_iteration / 100);
+}
+
+#[test]
+fn configurable_default_decimal_places() {
+    let mut ctx = Context::new();
+    assert_eq!(
+        evaluate("pi", &mut ctx).unwrap().get_main_result(),
+        "approx. 3.1415926536"
+    );
+    ctx.set_default_decimal_places(20);
+    ctx.set_precision(30);
+    assert_eq!(
+        evaluate("ln 2", &mut ctx).unwrap().get_main_result(),
+        "approx. 0.69314718055994530942"
+    );
+    assert_eq!(
+        evaluate("1/3 + i", &mut ctx).unwrap().get_main_result(),
+        "1/3 + i"
+    );
+    assert_eq!(
+        evaluate("sqrt 2 to 3 dp", &mut ctx)
+            .unwrap()
+            .get_main_result(),
+        "approx. 1.414"
+    );
+    ctx.set_default_decimal_places(2);
+    assert_eq!(
+        evaluate("sqrt 2", &mut ctx).unwrap().get_main_result(),
+        "approx. 1.41"
+    );
+    // no decimal point is printed when there are no decimal places
+    ctx.set_default_decimal_places(0);
+    assert_eq!(
+        evaluate("sqrt 2", &mut ctx).unwrap().get_main_result(),
+        "approx. 1"
+    );
+    assert_eq!(
+        evaluate("2i - (sqrt 2)/4", &mut ctx)
+            .unwrap()
+            .get_main_result(),
+        "approx. 0 + 2i"
+    );
+}
+
+#[test]
+fn configurable_rounding_mode() {
+    let mut ctx = Context::new();
+    assert_eq!(
+        evaluate("2/3", &mut ctx).unwrap().get_main_result(),
+        "approx. 0.6666666667"
+    );
+    ctx.set_rounding_mode(RoundingMode::TowardZero);
+    assert_eq!(
+        evaluate("2/3", &mut ctx).unwrap().get_main_result(),
+        "approx. 0.6666666666"
+    );
+    assert_eq!(
+        evaluate("-pi to 2 dp", &mut ctx).unwrap().get_main_result(),
+        "approx. -3.14"
+    );
+    ctx.set_rounding_mode(RoundingMode::AwayFromZero);
+    assert_eq!(
+        evaluate("-pi to 2 dp", &mut ctx).unwrap().get_main_result(),
+        "approx. -3.15"
+    );
+    ctx.set_rounding_mode(RoundingMode::Floor);
+    assert_eq!(
+        evaluate("1234 to 2 sf", &mut ctx)
+            .unwrap()
+            .get_main_result(),
+        "approx. 1200"
+    );
+    ctx.set_rounding_mode(RoundingMode::Ceil);
+    assert_eq!(
+        evaluate("1234 to 2 sf", &mut ctx)
+            .unwrap()
+            .get_main_result(),
+        "approx. 1300"
+    );
+    // ties round to even by default, but can round away from zero
+    ctx.set_rounding_mode(RoundingMode::HalfEven);
+    assert_eq!(
+        evaluate("2.5 to 0 dp", &mut ctx).unwrap().get_main_result(),
+        "approx. 2"
+    );
+    ctx.set_rounding_mode(RoundingMode::HalfUp);
+    assert_eq!(
+        evaluate("2.5 to 0 dp", &mut ctx).unwrap().get_main_result(),
+        "approx. 3"
+    );
+}
+
+#[test]
+fn configurable_recurring_digit_limit() {
+    let mut ctx = Context::new();
+    ctx.set_recurring_digit_limit(20);
+    assert_eq!(
+        evaluate("1/7 to float", &mut ctx)
+            .unwrap()
+            .get_main_result(),
+        "0.(142857)"
+    );
+    // the period of 1/97 is 96 digits long
+    assert_eq!(
+        evaluate("1/97 to float", &mut ctx)
+            .unwrap()
+            .get_main_result(),
+        "approx. 0.01030927835051546391"
+    );
+}
+
+#[test]
+fn configurable_leading_zero() {
+    let mut ctx = Context::new();
+    ctx.set_leading_zero(false);
+    for (input, expected) in [
+        ("1/2", ".5"),
+        ("-1/4", "-.25"),
+        ("0.5i", ".5i"),
+        ("1/3", "approx. .3333333333"),
+        ("1.5", "1.5"),
+        ("0", "0"),
+        ("0.5 to binary", ".1"),
+        ("0.5 to base 8", ".4"),
+    ] {
+        assert_eq!(
+            evaluate(input, &mut ctx).unwrap().get_main_result(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn configurable_trailing_zeros() {
+    let mut ctx = Context::new();
+    ctx.set_trailing_zeros(TrailingZeros::KeepOne);
+    for (input, expected) in [
+        ("2 to float", "2.0"),
+        ("5/4 to 4 dp", "1.25"),
+        ("2", "2"),
+        ("1/3 to float", "0.(3)"),
+    ] {
+        assert_eq!(
+            evaluate(input, &mut ctx).unwrap().get_main_result(),
+            expected
+        );
+    }
+    ctx.set_trailing_zeros(TrailingZeros::Pad);
+    for (input, expected) in [
+        ("5/4 to 4 dp", "1.2500"),
+        ("1.5 m to 3 dp", "1.500 m"),
+        ("asinh 1e100", "approx. 230.9516564800"),
+        ("2", "2"),
+    ] {
+        assert_eq!(
+            evaluate(input, &mut ctx).unwrap().get_main_result(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn zero_decimal_places() {
+    test_eval("5.5 to 0 dp", "approx. 6");
+    test_eval("-0.5 to 0 dp", "approx. 0");
+}